@@ -1,7 +1,11 @@
+use crate::application::inputs::request::RequestMetadata;
+
 pub struct SignUpInput {
     pub first_name: String,
     pub last_name: String,
     pub email: String,
     pub password: String,
-    pub password_confirmation: String,
+    /// The password typed a second time, checked against `password` when the client sent one.
+    pub password_confirmation: Option<String>,
+    pub metadata: RequestMetadata,
 }
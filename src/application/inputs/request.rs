@@ -0,0 +1,6 @@
+/// Where a request came from, as far as the HTTP layer could tell.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestMetadata {
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
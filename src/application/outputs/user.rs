@@ -0,0 +1,33 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::{entities::user::UserEntity, values::timestamp::Timestamp};
+
+/// The public projection of a [`UserEntity`], the only shape users are returned in by the API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[schema(description = "A registered user.")]
+pub struct UserView {
+    pub id: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    pub email_verified: bool,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: Timestamp,
+    #[schema(value_type = String, format = DateTime)]
+    pub updated_at: Timestamp,
+}
+
+impl From<&UserEntity> for UserView {
+    fn from(user: &UserEntity) -> Self {
+        Self {
+            id: user.id().to_string(),
+            first_name: user.first_name().to_string(),
+            last_name: user.last_name().to_string(),
+            email: user.email().to_string(),
+            email_verified: user.is_email_verified(),
+            created_at: user.created_at(),
+            updated_at: user.updated_at(),
+        }
+    }
+}
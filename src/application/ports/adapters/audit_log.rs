@@ -0,0 +1,142 @@
+use crate::domain::values::timestamp::Timestamp;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    SignUp,
+    SignInSucceeded,
+    SignInFailed,
+    PasswordChanged,
+    RoleChanged,
+    UserDeleted,
+}
+
+impl AuditAction {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::SignUp => "sign_up",
+            Self::SignInSucceeded => "sign_in_succeeded",
+            Self::SignInFailed => "sign_in_failed",
+            Self::PasswordChanged => "password_changed",
+            Self::RoleChanged => "role_changed",
+            Self::UserDeleted => "user_deleted",
+        }
+    }
+}
+
+impl std::str::FromStr for AuditAction {
+    type Err = AuditLogError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "sign_up" => Ok(Self::SignUp),
+            "sign_in_succeeded" => Ok(Self::SignInSucceeded),
+            "sign_in_failed" => Ok(Self::SignInFailed),
+            "password_changed" => Ok(Self::PasswordChanged),
+            "role_changed" => Ok(Self::RoleChanged),
+            "user_deleted" => Ok(Self::UserDeleted),
+            _ => Err(AuditLogError::UnknownAction(value.to_string())),
+        }
+    }
+}
+
+/// Something that happened and must be recorded, before the log assigns it a place in the chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEvent {
+    pub action: AuditAction,
+    pub actor: Option<String>,
+    pub target: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+/// A recorded [`AuditEvent`], chained to its predecessor through `previous_hash`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEntry {
+    pub sequence: u64,
+    pub action: AuditAction,
+    pub actor: Option<String>,
+    pub target: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub occurred_at: i64,
+    pub previous_hash: String,
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditQuery {
+    pub action: Option<AuditAction>,
+    pub actor: Option<String>,
+    pub target: Option<String>,
+    pub since: Option<Timestamp>,
+    pub until: Option<Timestamp>,
+    pub limit: Option<usize>,
+}
+
+impl AuditQuery {
+    #[must_use]
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        self.action.is_none_or(|action| action == entry.action)
+            && self
+                .actor
+                .as_ref()
+                .is_none_or(|actor| entry.actor.as_ref() == Some(actor))
+            && self
+                .target
+                .as_ref()
+                .is_none_or(|target| entry.target.as_ref() == Some(target))
+            && self
+                .since
+                .is_none_or(|since| Timestamp::from_unix_seconds(entry.occurred_at) >= since)
+            && self
+                .until
+                .is_none_or(|until| Timestamp::from_unix_seconds(entry.occurred_at) <= until)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AuditLogError {
+    Storage(String),
+    Tampered { sequence: u64 },
+    UnknownAction(String),
+}
+
+impl std::fmt::Display for AuditLogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Storage(err) => write!(f, "Audit log storage failed: {err}"),
+            Self::Tampered { sequence } => {
+                write!(f, "Audit log chain is broken at entry {sequence}")
+            }
+            Self::UnknownAction(action) => write!(f, "Unknown audit action '{action}'"),
+        }
+    }
+}
+
+impl std::error::Error for AuditLogError {}
+
+pub trait AuditLogPort: Send + Sync {
+    /// Appends an event to the log, timestamping it and chaining it to the previous entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AuditLogError::Storage`] if the entry cannot be persisted.
+    fn record(&self, event: AuditEvent) -> Result<AuditEntry, AuditLogError>;
+
+    /// Returns the entries matching `query`, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AuditLogError::Storage`] if the log cannot be read.
+    fn query(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, AuditLogError>;
+
+    /// Recomputes every hash in the log to detect altered, removed or reordered entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The log cannot be read (`AuditLogError::Storage`)
+    /// - An entry does not match its recorded hash or predecessor (`AuditLogError::Tampered`)
+    fn verify_chain(&self) -> Result<(), AuditLogError>;
+}
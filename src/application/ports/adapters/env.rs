@@ -3,13 +3,19 @@ use std::str::FromStr;
 #[derive(Debug)]
 pub enum EnvError {
     EnvNotInitialized,
-    FileNotFound,
     FileNotLoaded,
-    MissingVariables(Vec<String>),
+    FileParsing {
+        path: String,
+        reason: String,
+    },
+    InvalidArgument(String),
+    SecretFileUnreadable {
+        path: String,
+        reason: String,
+    },
     VariableNotSet(&'static str),
     VariableParsing {
         key: &'static str,
-        value: String,
         parsing_type: &'static str,
     },
 }
@@ -21,46 +27,81 @@ impl std::fmt::Display for EnvError {
                 f,
                 "Environment not initialized. Ensure the env adapter is set up before use."
             ),
-            Self::FileNotFound => write!(f, ".env file not found"),
-            Self::FileNotLoaded => write!(f, ".env file not loaded"),
-            Self::MissingVariables(variables) => {
-                if variables.len() > 1 {
-                    write!(f, "{} are not set in .env file", variables.join(", "))
-                } else {
-                    write!(f, "{} is not set in .env file", variables.join(" "))
-                }
+            Self::FileNotLoaded => write!(f, "configuration sources not loaded"),
+            Self::FileParsing { path, reason } => {
+                write!(f, "Could not parse configuration file '{path}': {reason}")
+            }
+            Self::InvalidArgument(argument) => {
+                write!(f, "Invalid command-line argument '{argument}'")
+            }
+            Self::SecretFileUnreadable { path, reason } => {
+                write!(f, "Could not read secret file '{path}': {reason}")
             }
             Self::VariableNotSet(key) => write!(f, "env variable '{key}' not set"),
-            Self::VariableParsing {
-                key,
-                value,
-                parsing_type,
-            } => write!(
-                f,
-                "Could not parse env variable '{key}' with value '{value}' to '{parsing_type}'"
-            ),
+            // The value is left out on purpose: it may be a secret.
+            Self::VariableParsing { key, parsing_type } => {
+                write!(
+                    f,
+                    "Could not parse env variable '{key}' to '{parsing_type}'"
+                )
+            }
         }
     }
 }
 
 impl std::error::Error for EnvError {}
 
+/// The layer a configuration value was taken from, listed from lowest to highest precedence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    Default,
+    ConfigFile(String),
+    ProfileFile(String),
+    DotEnv(String),
+    Environment,
+    CommandLine,
+    SecretFile(String),
+}
+
+impl std::fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::ConfigFile(path) | Self::ProfileFile(path) | Self::DotEnv(path) => {
+                write!(f, "{path}")
+            }
+            Self::Environment => write!(f, "environment"),
+            Self::CommandLine => write!(f, "command line"),
+            Self::SecretFile(path) => write!(f, "secret file {path}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedValue {
+    pub value: String,
+    pub source: ConfigSource,
+}
+
 pub trait EnvPort {
-    /// Loads the environment variables from the `.env` file.
+    /// Loads every configuration layer. Layers whose file does not exist are skipped.
     ///
     /// # Errors
     ///
-    /// Returns an [`EnvError::FileNotFound`] if the `.env` file cannot be found.
+    /// Returns an error if:
+    /// - A configuration file exists but cannot be parsed (`EnvError::FileParsing`)
+    /// - A command-line flag is malformed (`EnvError::InvalidArgument`)
     fn load_env_file(&mut self) -> Result<(), EnvError>;
 
-    /// Checks that all required environment variables are set and valid.
+    /// Resolves a key through every loaded layer, the highest-precedence layer winning. A layer
+    /// that sets `<KEY>_FILE` instead of `<KEY>` provides the contents of that file.
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The `.env` file has not been loaded (`EnvError::FileNotLoaded`)
-    /// - Any required environment variable is missing (`EnvError::MissingVariables`)
-    fn check_env_vars(&self) -> Result<(), EnvError>;
+    /// - The layers have not been loaded (`EnvError::FileNotLoaded`)
+    /// - The file named by `<KEY>_FILE` cannot be read (`EnvError::SecretFileUnreadable`)
+    fn lookup(&self, key: &str) -> Result<Option<ResolvedValue>, EnvError>;
 
     /// Retrieves an environment variable and attempts to parse it to the specified type.
     ///
@@ -71,26 +112,18 @@ pub trait EnvPort {
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The `.env` file has not been loaded (`EnvError::FileNotLoaded`)
+    /// - The layers have not been loaded (`EnvError::FileNotLoaded`)
     /// - The variable is not set (`EnvError::VariableNotSet`)
     /// - The value cannot be parsed into type `T` (`EnvError::VariableParsing`)
-    fn get_env_var<T: FromStr>(&self, key: &'static str) -> Result<T, EnvError>;
+    fn get_env_var<T: FromStr>(&self, key: &'static str) -> Result<T, EnvError> {
+        let variable = self.lookup(key)?.ok_or(EnvError::VariableNotSet(key))?;
 
-    /// Retrieves the `SERVER_HOST` environment variable.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - The `.env` file has not been loaded
-    /// - The `SERVER_HOST` variable is missing or cannot be parsed as a string
-    fn get_server_host(&self) -> Result<String, EnvError>;
-
-    /// Retrieves the `SERVER_PORT` environment variable.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - The `.env` file has not been loaded
-    /// - The `SERVER_PORT` variable is missing or cannot be parsed as a `u16`
-    fn get_server_port(&self) -> Result<u16, EnvError>;
+        variable
+            .value
+            .parse::<T>()
+            .map_err(|_| EnvError::VariableParsing {
+                key,
+                parsing_type: std::any::type_name::<T>(),
+            })
+    }
 }
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthCheckError {
+    Unhealthy(String),
+}
+
+impl std::fmt::Display for HealthCheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unhealthy(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+impl std::error::Error for HealthCheckError {}
+
+/// A dependency the application needs in order to serve traffic.
+#[async_trait]
+pub trait HealthCheckPort: Send + Sync {
+    /// Short identifier shown in the health report, such as `database`.
+    fn name(&self) -> &'static str;
+
+    /// Probes the dependency once. Callers bound the probe with their own timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HealthCheckError::Unhealthy`] describing why the dependency cannot be used.
+    async fn check(&self) -> Result<(), HealthCheckError>;
+}
@@ -1,3 +1,26 @@
+/// A value that is not an id of the format the generator hands out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidIdError {
+    pub value: String,
+    pub expected: &'static str,
+}
+
+impl std::fmt::Display for InvalidIdError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is not a valid {}", self.value, self.expected)
+    }
+}
+
+impl std::error::Error for InvalidIdError {}
+
 pub trait IdGeneratorPort: Send + Sync {
     fn generate_id(&self) -> String;
+
+    /// Checks that `value` could have been generated by this adapter and returns it in canonical
+    /// form, so ids taken from requests can be rejected before they reach a repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidIdError`] if `value` is not in this adapter's format.
+    fn parse_id(&self, value: &str) -> Result<String, InvalidIdError>;
 }
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignUpOutcome {
+    Success,
+    PasswordMismatch,
+    UserAlreadyExists,
+    Internal,
+}
+
+impl SignUpOutcome {
+    pub const ALL: [Self; 4] = [
+        Self::Success,
+        Self::PasswordMismatch,
+        Self::UserAlreadyExists,
+        Self::Internal,
+    ];
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::PasswordMismatch => "password_mismatch",
+            Self::UserAlreadyExists => "user_already_exists",
+            Self::Internal => "internal",
+        }
+    }
+}
+
+/// Records what the application does, leaving the exposition format to the adapter.
+pub trait MetricsPort: Send + Sync {
+    /// `route` is the matched route template, such as `/admin/audit-log`, never the raw path.
+    fn observe_http_request(&self, method: &str, route: &str, status: u16, duration: Duration);
+
+    /// `version` names the API version the request was routed to, such as `v1`.
+    fn count_api_request(&self, version: &'static str);
+
+    fn count_sign_up(&self, outcome: SignUpOutcome);
+
+    fn observe_password_hash(&self, duration: Duration);
+
+    /// `operation` names the repository or unit-of-work call, such as `find_by_email`.
+    fn observe_repository_call(&self, operation: &'static str, duration: Duration);
+
+    /// `policy` names the rate limit whose store failed, such as `sign_up`.
+    fn count_rate_limit_store_failure(&self, policy: &'static str);
+}
@@ -1,3 +1,25 @@
+/// A password the hasher could not turn into a hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordHashError(pub String);
+
+impl std::fmt::Display for PasswordHashError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not hash the password: {}", self.0)
+    }
+}
+
+impl std::error::Error for PasswordHashError {}
+
 pub trait PasswordHasherPort: Send + Sync {
-    fn hash_password(&self, password: String) -> String;
+    /// Hashes `password` into a string that [`Self::verify_password`] can check it against.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PasswordHashError`] if the hasher is misconfigured or its source of randomness
+    /// is unavailable.
+    fn hash_password(&self, password: String) -> Result<String, PasswordHashError>;
+
+    /// Tells whether `password` is the one `password_hash` was produced from. Hashes this adapter
+    /// cannot parse never match.
+    fn verify_password(&self, password: &str, password_hash: &str) -> bool;
 }
@@ -0,0 +1,105 @@
+/// How many requests a key may make per period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quota {
+    pub limit: u32,
+    pub period_seconds: u32,
+}
+
+/// The outcome of counting one request against a [`Quota`], with what the `RateLimit-*` headers
+/// report to the caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    /// Seconds until the quota is fully available again.
+    pub reset_seconds: u64,
+    /// Seconds until a denied request may be retried.
+    pub retry_after_seconds: Option<u64>,
+}
+
+impl Quota {
+    /// Applies the generic cell rate algorithm (GCRA) to one request made at `now_ms`.
+    ///
+    /// The only state a key needs is its theoretical arrival time (TAT), in milliseconds. Takes
+    /// the one stored for the key, if any, and returns the decision along with the TAT to store,
+    /// which is unchanged when the request is denied. Stores call this inside whatever atomic
+    /// read-modify-write their backend offers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero, which callers treat as the limit being disabled.
+    #[must_use]
+    pub fn apply(self, stored_tat_ms: Option<i64>, now_ms: i64) -> (RateLimitDecision, i64) {
+        assert!(self.limit > 0, "a quota must allow at least one request");
+
+        let period_ms = i64::from(self.period_seconds) * 1000;
+        let emission_interval_ms = (period_ms / i64::from(self.limit)).max(1);
+        let tat_ms = stored_tat_ms.map_or(now_ms, |tat_ms| tat_ms.max(now_ms));
+        let next_tat_ms = tat_ms + emission_interval_ms;
+        let allowed_at_ms = next_tat_ms - period_ms;
+
+        if now_ms < allowed_at_ms {
+            return (
+                RateLimitDecision {
+                    allowed: false,
+                    limit: self.limit,
+                    remaining: 0,
+                    reset_seconds: ceil_seconds(tat_ms - now_ms),
+                    retry_after_seconds: Some(ceil_seconds(allowed_at_ms - now_ms)),
+                },
+                tat_ms,
+            );
+        }
+
+        let remaining = (now_ms - allowed_at_ms) / emission_interval_ms;
+
+        (
+            RateLimitDecision {
+                allowed: true,
+                limit: self.limit,
+                remaining: u32::try_from(remaining)
+                    .unwrap_or(u32::MAX)
+                    .min(self.limit - 1),
+                reset_seconds: ceil_seconds(next_tat_ms - now_ms),
+                retry_after_seconds: None,
+            },
+            next_tat_ms,
+        )
+    }
+}
+
+fn ceil_seconds(milliseconds: i64) -> u64 {
+    u64::try_from(milliseconds.max(0)).map_or(0, |milliseconds| milliseconds.div_ceil(1000))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RateLimitError {
+    Storage(String),
+}
+
+impl std::fmt::Display for RateLimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Storage(err) => write!(f, "Rate limit storage failed: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for RateLimitError {}
+
+/// Keeps the per-key rate limiting state. In-process stores suit a single instance; shared ones
+/// make every instance of a deployment count against the same quota.
+pub trait RateLimitStorePort: Send + Sync {
+    /// Counts one request made by `key` at `now_ms` against `quota`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RateLimitError::Storage`] if the state cannot be read or written.
+    fn acquire(
+        &self,
+        key: &str,
+        quota: Quota,
+        now_ms: i64,
+    ) -> Result<RateLimitDecision, RateLimitError>;
+}
@@ -1,3 +1,42 @@
+use std::time::Duration;
+
+use crate::domain::values::timestamp::Timestamp;
+
+/// A reading of a clock that never goes backwards, only comparable with readings of the same
+/// clock.
+///
+/// The wall clock can jump when it is corrected, so elapsed times, such as lockouts and token
+/// lifetimes within a process, are measured with these instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MonotonicInstant(Duration);
+
+impl MonotonicInstant {
+    /// The reading `elapsed` after the clock's own, arbitrary, origin.
+    #[must_use]
+    pub const fn from_origin(elapsed: Duration) -> Self {
+        Self(elapsed)
+    }
+
+    /// The time elapsed from `earlier` to this reading, zero if `earlier` is later.
+    #[must_use]
+    pub const fn saturating_duration_since(self, earlier: Self) -> Duration {
+        self.0.saturating_sub(earlier.0)
+    }
+
+    #[must_use]
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        self.0.checked_add(duration).map(Self)
+    }
+}
+
 pub trait TimePort: Send + Sync {
-    fn utc_now(&self) -> i64;
+    /// The current UTC time, to the millisecond.
+    fn now(&self) -> Timestamp;
+
+    fn monotonic_now(&self) -> MonotonicInstant;
+
+    /// The time elapsed since `earlier`, a reading of this same clock.
+    fn elapsed_since(&self, earlier: MonotonicInstant) -> Duration {
+        self.monotonic_now().saturating_duration_since(earlier)
+    }
 }
@@ -4,6 +4,6 @@ use crate::{
 };
 
 #[async_trait::async_trait]
-pub trait SignUpPort {
+pub trait SignUpPort: Send + Sync {
     async fn perform(&self, input: SignUpInput) -> Result<UserEntity, DomainError>;
 }
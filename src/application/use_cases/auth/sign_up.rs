@@ -1,28 +1,35 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use crate::{
     application::{
-        inputs::auth::sign_up::SignUpInput,
+        inputs::{auth::sign_up::SignUpInput, request::RequestMetadata},
         ports::{
             adapters::{
-                id_generator::IdGeneratorPort, password_hasher::PasswordHasherPort, time::TimePort,
+                audit_log::{AuditAction, AuditEvent, AuditLogPort},
+                id_generator::IdGeneratorPort,
+                metrics::{MetricsPort, SignUpOutcome},
+                password_hasher::PasswordHasherPort,
+                time::TimePort,
             },
             use_cases::auth::sign_up::SignUpPort,
         },
     },
     domain::{
-        dtos::user::{CreateUserDto, FindUserByEmailDto},
-        entities::user::UserEntity,
+        dtos::user::FindUserByEmailDto,
+        entities::user::{NewUser, UserEntity},
         errors::domain::DomainError,
-        repositories::user::UserPersistencePort,
+        repositories::{unit_of_work::UnitOfWorkPort, user::UserPersistencePort},
     },
 };
 
+#[derive(Clone)]
 pub struct SignUpUseCase {
     id_generator: Arc<dyn IdGeneratorPort>,
     password_hasher: Arc<dyn PasswordHasherPort>,
     time: Arc<dyn TimePort>,
-    repository: Arc<dyn UserPersistencePort>,
+    unit_of_work: Arc<dyn UnitOfWorkPort>,
+    audit_log: Arc<dyn AuditLogPort>,
+    metrics: Arc<dyn MetricsPort>,
 }
 
 impl SignUpUseCase {
@@ -30,30 +37,83 @@ impl SignUpUseCase {
         id_generator: Arc<dyn IdGeneratorPort>,
         password_hasher: Arc<dyn PasswordHasherPort>,
         time: Arc<dyn TimePort>,
-        repository: Arc<dyn UserPersistencePort>,
+        unit_of_work: Arc<dyn UnitOfWorkPort>,
+        audit_log: Arc<dyn AuditLogPort>,
+        metrics: Arc<dyn MetricsPort>,
     ) -> Self {
         Self {
             id_generator,
             password_hasher,
             time,
-            repository,
+            unit_of_work,
+            audit_log,
+            metrics,
         }
     }
-}
 
-#[async_trait::async_trait]
-impl SignUpPort for SignUpUseCase {
-    async fn perform(&self, input: SignUpInput) -> Result<UserEntity, DomainError> {
-        if input.password != input.password_confirmation {
+    fn sign_up(&self, mut input: SignUpInput) -> Result<UserEntity, DomainError> {
+        if input
+            .password_confirmation
+            .as_ref()
+            .is_some_and(|confirmation| *confirmation != input.password)
+        {
             return Err(DomainError::PasswordMismatch);
         }
 
+        // Hashing takes tens of milliseconds, so it happens before the transaction takes the
+        // database's write lock rather than holding every other writer up.
+        let password_hash = self.hash_password(std::mem::take(&mut input.password))?;
+
+        let transaction = self
+            .unit_of_work
+            .begin()
+            .map_err(|err| DomainError::Internal(err.to_string()))?;
+
+        let metadata = input.metadata.clone();
+
+        match self.register_user(transaction.users().as_ref(), input, password_hash) {
+            Ok(user_entity) => {
+                transaction.commit().map_err(map_persistence_error)?;
+                self.record_sign_up(&user_entity, metadata);
+
+                Ok(user_entity)
+            }
+            Err(err) => {
+                // The original error is what the caller must act on; a failed rollback only
+                // concerns the operator, as the transaction is discarded either way.
+                if let Err(rollback_err) = transaction.rollback() {
+                    tracing::error!(error = %rollback_err, "Could not roll back the sign-up");
+                }
+
+                Err(err)
+            }
+        }
+    }
+
+    fn hash_password(&self, password: String) -> Result<String, DomainError> {
+        let hashing_started_at = Instant::now();
+        let password_hash = self
+            .password_hasher
+            .hash_password(password)
+            .map_err(|err| DomainError::Internal(err.to_string()))?;
+
+        self.metrics
+            .observe_password_hash(hashing_started_at.elapsed());
+
+        Ok(password_hash)
+    }
+
+    fn register_user(
+        &self,
+        repository: &dyn UserPersistencePort,
+        input: SignUpInput,
+        password_hash: String,
+    ) -> Result<UserEntity, DomainError> {
         let find_user_by_email_dto = FindUserByEmailDto {
             email: input.email.clone(),
         };
 
-        let found_user = self
-            .repository
+        let found_user = repository
             .find_by_email(find_user_by_email_dto)
             .map_err(|err| DomainError::Internal(err.to_string()))?;
 
@@ -61,54 +121,134 @@ impl SignUpPort for SignUpUseCase {
             return Err(DomainError::UserAlreadyExists);
         }
 
-        let create_user_dto = CreateUserDto {
-            id: self.id_generator.generate_id(),
-            first_name: input.first_name,
-            last_name: input.last_name,
-            email: input.email,
-            password_hash: self.password_hasher.hash_password(input.password),
-            created_at: self.time.utc_now(),
-        };
+        let user = UserEntity::register(
+            NewUser {
+                id: self.id_generator.generate_id(),
+                first_name: input.first_name,
+                last_name: input.last_name,
+                email: input.email,
+                password_hash,
+            },
+            self.time.now(),
+        );
 
-        let user_entity = match self.repository.create(create_user_dto) {
-            Ok(entity) => entity,
-            Err(err) => return Err(DomainError::Internal(err.to_string())),
-        };
+        repository.create(user).map_err(map_persistence_error)
+    }
+
+    /// Recorded once the transaction committed, as the audit log cannot be rolled back: an entry
+    /// is never written for a user who does not exist. The user does exist by then, so a failure
+    /// to record is reported to the operator instead of failing the sign-up.
+    fn record_sign_up(&self, user: &UserEntity, metadata: RequestMetadata) {
+        let recorded = self.audit_log.record(AuditEvent {
+            action: AuditAction::SignUp,
+            actor: Some(user.id().to_string()),
+            target: Some(user.id().to_string()),
+            ip_address: metadata.ip_address,
+            user_agent: metadata.user_agent,
+        });
 
-        Ok(user_entity)
+        if let Err(err) = recorded {
+            tracing::error!(error = %err, user_id = user.id(), "Could not audit the sign-up");
+        }
+    }
+}
+
+/// A uniqueness conflict reported by the persistence layer means a concurrent sign-up claimed the
+/// e-mail after our lookup, so it is surfaced the same way as a user found by that lookup.
+fn map_persistence_error(err: DomainError) -> DomainError {
+    match err {
+        DomainError::Conflict(_) => DomainError::UserAlreadyExists,
+        err => DomainError::Internal(err.to_string()),
+    }
+}
+
+const fn sign_up_outcome(result: &Result<UserEntity, DomainError>) -> SignUpOutcome {
+    match result {
+        Ok(_) => SignUpOutcome::Success,
+        Err(DomainError::PasswordMismatch) => SignUpOutcome::PasswordMismatch,
+        Err(DomainError::UserAlreadyExists) => SignUpOutcome::UserAlreadyExists,
+        Err(DomainError::Conflict(_) | DomainError::Internal(_)) => SignUpOutcome::Internal,
+    }
+}
+
+#[async_trait::async_trait]
+impl SignUpPort for SignUpUseCase {
+    // The input is skipped as a whole: it holds the password in clear.
+    #[tracing::instrument(name = "sign_up", skip_all, fields(outcome = tracing::field::Empty))]
+    async fn perform(&self, input: SignUpInput) -> Result<UserEntity, DomainError> {
+        // Hashing takes tens of milliseconds of CPU and the persistence adapters block on I/O,
+        // so the sign-up runs off the async workers.
+        let use_case = self.clone();
+        let span = tracing::Span::current();
+        let result = tokio::task::spawn_blocking(move || span.in_scope(|| use_case.sign_up(input)))
+            .await
+            .unwrap_or_else(|err| Err(DomainError::Internal(err.to_string())));
+        let outcome = sign_up_outcome(&result);
+
+        tracing::Span::current().record("outcome", outcome.as_str());
+        self.metrics.count_sign_up(outcome);
+
+        result
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use mockall::mock;
-    use std::sync::Arc;
+    use mockall::{Sequence, mock};
+    use std::{sync::Arc, time::Duration};
 
     use crate::{
         application::{
-            inputs::auth::sign_up::SignUpInput,
+            inputs::{auth::sign_up::SignUpInput, request::RequestMetadata},
             ports::{
                 adapters::{
-                    id_generator::IdGeneratorPort, password_hasher::PasswordHasherPort,
-                    time::TimePort,
+                    audit_log::{
+                        AuditAction, AuditEntry, AuditEvent, AuditLogError, AuditLogPort,
+                        AuditQuery,
+                    },
+                    id_generator::{IdGeneratorPort, InvalidIdError},
+                    metrics::{MetricsPort, SignUpOutcome},
+                    password_hasher::{PasswordHashError, PasswordHasherPort},
+                    time::{MonotonicInstant, TimePort},
                 },
                 use_cases::auth::sign_up::SignUpPort,
             },
             use_cases::auth::sign_up::SignUpUseCase,
         },
         domain::{
-            dtos::user::{CreateUserDto, FindUserByEmailDto},
-            entities::user::UserEntity,
+            dtos::user::FindUserByEmailDto,
+            entities::user::{StoredUser, UserEntity},
             errors::domain::DomainError,
-            repositories::user::UserPersistencePort,
+            repositories::{
+                unit_of_work::{TransactionPort, UnitOfWorkPort},
+                user::UserPersistencePort,
+            },
+            values::timestamp::Timestamp,
         },
     };
 
+    const NOW: Timestamp = Timestamp::from_unix_seconds(1_000_000);
+
+    /// The user signing up as John Doe registers, given the ids, hashes and time mocked below.
+    fn registered_user() -> UserEntity {
+        UserEntity::restore(StoredUser {
+            id: "generated_id".to_string(),
+            first_name: "John".to_string(),
+            last_name: "Doe".to_string(),
+            email: "john.doe@mail.com".to_string(),
+            password_hash: "password_hash".to_string(),
+            email_verified_at: None,
+            created_at: NOW,
+            updated_at: NOW,
+        })
+    }
+
     mock! {
         pub IdGeneratorPort {}
 
         impl IdGeneratorPort for IdGeneratorPort {
             fn generate_id(&self) -> String;
+            fn parse_id(&self, value: &str) -> Result<String, InvalidIdError>;
         }
     }
 
@@ -116,7 +256,8 @@ mod tests {
         pub PasswordHasherPort {}
 
         impl PasswordHasherPort for PasswordHasherPort {
-            fn hash_password(&self, password: String) -> String;
+            fn hash_password(&self, password: String) -> Result<String, PasswordHashError>;
+            fn verify_password(&self, password: &str, password_hash: &str) -> bool;
         }
     }
 
@@ -124,7 +265,8 @@ mod tests {
         pub TimePort {}
 
         impl TimePort for TimePort {
-            fn utc_now(&self) -> i64;
+            fn now(&self) -> Timestamp;
+            fn monotonic_now(&self) -> MonotonicInstant;
         }
     }
 
@@ -132,11 +274,114 @@ mod tests {
         pub UserPersistencePort {}
 
         impl UserPersistencePort for UserPersistencePort {
-            fn create(&self, dto: CreateUserDto) -> Result<UserEntity, DomainError>;
+            fn create(&self, user: UserEntity) -> Result<UserEntity, DomainError>;
             fn find_by_email(&self, dto: FindUserByEmailDto) -> Result<Option<UserEntity>, DomainError>;
         }
     }
 
+    mock! {
+        pub AuditLogPort {}
+
+        impl AuditLogPort for AuditLogPort {
+            fn record(&self, event: AuditEvent) -> Result<AuditEntry, AuditLogError>;
+            fn query(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, AuditLogError>;
+            fn verify_chain(&self) -> Result<(), AuditLogError>;
+        }
+    }
+
+    mock! {
+        pub MetricsPort {}
+
+        impl MetricsPort for MetricsPort {
+            fn observe_http_request(&self, method: &str, route: &str, status: u16, duration: Duration);
+            fn count_api_request(&self, version: &'static str);
+            fn count_sign_up(&self, outcome: SignUpOutcome);
+            fn observe_password_hash(&self, duration: Duration);
+            fn observe_repository_call(&self, operation: &'static str, duration: Duration);
+            fn count_rate_limit_store_failure(&self, policy: &'static str);
+        }
+    }
+
+    mock! {
+        pub TransactionPort {}
+
+        impl TransactionPort for TransactionPort {
+            fn users(&self) -> Arc<dyn UserPersistencePort>;
+            fn commit(&self) -> Result<(), DomainError>;
+            fn rollback(&self) -> Result<(), DomainError>;
+        }
+    }
+
+    mock! {
+        pub UnitOfWorkPort {}
+
+        impl UnitOfWorkPort for UnitOfWorkPort {
+            fn begin(&self) -> Result<Box<dyn TransactionPort>, DomainError>;
+        }
+    }
+
+    fn unit_of_work(
+        repository: MockUserPersistencePort,
+        mut transaction: MockTransactionPort,
+    ) -> MockUnitOfWorkPort {
+        let repository: Arc<dyn UserPersistencePort> = Arc::new(repository);
+
+        transaction
+            .expect_users()
+            .returning(move || Arc::clone(&repository));
+
+        let mut unit_of_work = MockUnitOfWorkPort::default();
+
+        unit_of_work
+            .expect_begin()
+            .times(1)
+            .return_once(move || Ok(Box::new(transaction)));
+
+        unit_of_work
+    }
+
+    fn password_hasher() -> MockPasswordHasherPort {
+        let mut password_hasher = MockPasswordHasherPort::default();
+
+        password_hasher
+            .expect_hash_password()
+            .times(1)
+            .returning(|_| Ok("password_hash".to_string()));
+
+        password_hasher
+    }
+
+    fn metrics(outcome: SignUpOutcome, password_hashes: usize) -> MockMetricsPort {
+        let mut metrics = MockMetricsPort::default();
+
+        metrics
+            .expect_count_sign_up()
+            .withf(move |recorded| *recorded == outcome)
+            .times(1)
+            .return_const(());
+
+        metrics
+            .expect_observe_password_hash()
+            .times(password_hashes)
+            .return_const(());
+
+        metrics
+    }
+
+    fn audit_entry(event: AuditEvent) -> AuditEntry {
+        AuditEntry {
+            sequence: 1,
+            action: event.action,
+            actor: event.actor,
+            target: event.target,
+            ip_address: event.ip_address,
+            user_agent: event.user_agent,
+            occurred_at: 1_000_000,
+            previous_hash: "previous_hash".to_string(),
+            hash: "hash".to_string(),
+        }
+    }
+
     #[tokio::test]
     async fn should_successfully_sign_up_user() {
         let mut id_generator = MockIdGeneratorPort::default();
@@ -151,11 +396,11 @@ mod tests {
         password_hasher
             .expect_hash_password()
             .times(1)
-            .returning(|_| "password_hash".to_string());
+            .returning(|_| Ok("password_hash".to_string()));
 
         let mut time = MockTimePort::default();
 
-        time.expect_utc_now().times(1).returning(|| 1_000_000);
+        time.expect_now().times(1).returning(|| NOW);
 
         let mut repository = MockUserPersistencePort::default();
 
@@ -164,22 +409,28 @@ mod tests {
             .times(1)
             .returning(|_| Ok(None));
 
-        repository.expect_create().times(1).returning(|_| {
-            Ok(UserEntity::new(
-                "John".to_string(),
-                "Doe".to_string(),
-                "john.doe@mail.com".to_string(),
-                "SuperSecret123".to_string(),
-                1_000_000,
-                1_000_000,
-            ))
-        });
+        repository.expect_create().times(1).returning(Ok);
+
+        let mut transaction = MockTransactionPort::default();
+
+        transaction.expect_commit().times(1).returning(|| Ok(()));
+        transaction.expect_rollback().never();
+
+        let mut audit_log = MockAuditLogPort::default();
+
+        audit_log
+            .expect_record()
+            .withf(|event| event.action == AuditAction::SignUp)
+            .times(1)
+            .returning(|event| Ok(audit_entry(event)));
 
         let use_case = SignUpUseCase::new(
             Arc::new(id_generator),
             Arc::new(password_hasher),
             Arc::new(time),
-            Arc::new(repository),
+            Arc::new(unit_of_work(repository, transaction)),
+            Arc::new(audit_log),
+            Arc::new(metrics(SignUpOutcome::Success, 1)),
         );
 
         let input = SignUpInput {
@@ -187,7 +438,8 @@ mod tests {
             last_name: "Doe".to_string(),
             email: "john.doe@mail.com".to_string(),
             password: "SuperSecret123".to_string(),
-            password_confirmation: "SuperSecret123".to_string(),
+            password_confirmation: Some("SuperSecret123".to_string()),
+            metadata: RequestMetadata::default(),
         };
 
         let result = use_case.perform(input).await;
@@ -196,17 +448,7 @@ mod tests {
 
         let user_entity = result.unwrap();
 
-        assert_eq!(
-            user_entity,
-            UserEntity::new(
-                "John".to_string(),
-                "Doe".to_string(),
-                "john.doe@mail.com".to_string(),
-                "SuperSecret123".to_string(),
-                1_000_000,
-                1_000_000,
-            )
-        );
+        assert_eq!(user_entity, registered_user());
     }
 
     #[tokio::test]
@@ -214,13 +456,19 @@ mod tests {
         let id_generator = MockIdGeneratorPort::default();
         let password_hasher = MockPasswordHasherPort::default();
         let time = MockTimePort::default();
-        let repository = MockUserPersistencePort::default();
+        let mut unit_of_work = MockUnitOfWorkPort::default();
+
+        unit_of_work.expect_begin().never();
+
+        let audit_log = MockAuditLogPort::default();
 
         let use_case = SignUpUseCase::new(
             Arc::new(id_generator),
             Arc::new(password_hasher),
             Arc::new(time),
-            Arc::new(repository),
+            Arc::new(unit_of_work),
+            Arc::new(audit_log),
+            Arc::new(metrics(SignUpOutcome::PasswordMismatch, 0)),
         );
 
         let input = SignUpInput {
@@ -228,7 +476,8 @@ mod tests {
             last_name: "Doe".to_string(),
             email: "john.doe@mail.com".to_string(),
             password: "SuperSecret123".to_string(),
-            password_confirmation: "SuperSecret1234".to_string(),
+            password_confirmation: Some("SuperSecret1234".to_string()),
+            metadata: RequestMetadata::default(),
         };
 
         let result = use_case.perform(input).await;
@@ -243,27 +492,30 @@ mod tests {
     #[tokio::test]
     async fn should_return_error_if_user_already_exists() {
         let id_generator = MockIdGeneratorPort::default();
-        let password_hasher = MockPasswordHasherPort::default();
+        let password_hasher = password_hasher();
         let time = MockTimePort::default();
 
         let mut repository = MockUserPersistencePort::default();
 
-        repository.expect_find_by_email().times(1).returning(|_| {
-            Ok(Some(UserEntity::new(
-                "John".to_string(),
-                "Doe".to_string(),
-                "john.doe@mail.com".to_string(),
-                "SuperSecret123".to_string(),
-                1_000_000,
-                1_000_000,
-            )))
-        });
+        repository
+            .expect_find_by_email()
+            .times(1)
+            .returning(|_| Ok(Some(registered_user())));
+
+        let mut transaction = MockTransactionPort::default();
+
+        transaction.expect_commit().never();
+        transaction.expect_rollback().times(1).returning(|| Ok(()));
+
+        let audit_log = MockAuditLogPort::default();
 
         let use_case = SignUpUseCase::new(
             Arc::new(id_generator),
             Arc::new(password_hasher),
             Arc::new(time),
-            Arc::new(repository),
+            Arc::new(unit_of_work(repository, transaction)),
+            Arc::new(audit_log),
+            Arc::new(metrics(SignUpOutcome::UserAlreadyExists, 1)),
         );
 
         let input = SignUpInput {
@@ -271,7 +523,8 @@ mod tests {
             last_name: "Doe".to_string(),
             email: "john.doe@mail.com".to_string(),
             password: "SuperSecret123".to_string(),
-            password_confirmation: "SuperSecret123".to_string(),
+            password_confirmation: Some("SuperSecret123".to_string()),
+            metadata: RequestMetadata::default(),
         };
 
         let result = use_case.perform(input).await;
@@ -283,10 +536,51 @@ mod tests {
         assert_eq!(result_err, DomainError::UserAlreadyExists);
     }
 
+    #[tokio::test]
+    async fn should_return_the_original_error_if_rollback_fails() {
+        let mut repository = MockUserPersistencePort::default();
+
+        repository
+            .expect_find_by_email()
+            .times(1)
+            .returning(|_| Ok(Some(registered_user())));
+
+        let mut transaction = MockTransactionPort::default();
+
+        transaction.expect_commit().never();
+        transaction
+            .expect_rollback()
+            .times(1)
+            .returning(|| Err(DomainError::Internal("Rollback failed".to_string())));
+
+        let use_case = SignUpUseCase::new(
+            Arc::new(MockIdGeneratorPort::default()),
+            Arc::new(password_hasher()),
+            Arc::new(MockTimePort::default()),
+            Arc::new(unit_of_work(repository, transaction)),
+            Arc::new(MockAuditLogPort::default()),
+            Arc::new(metrics(SignUpOutcome::UserAlreadyExists, 1)),
+        );
+
+        let input = SignUpInput {
+            first_name: "John".to_string(),
+            last_name: "Doe".to_string(),
+            email: "john.doe@mail.com".to_string(),
+            password: "SuperSecret123".to_string(),
+            password_confirmation: Some("SuperSecret123".to_string()),
+            metadata: RequestMetadata::default(),
+        };
+
+        assert_eq!(
+            use_case.perform(input).await.unwrap_err(),
+            DomainError::UserAlreadyExists
+        );
+    }
+
     #[tokio::test]
     async fn should_return_error_if_find_by_email_fails() {
         let id_generator = MockIdGeneratorPort::default();
-        let password_hasher = MockPasswordHasherPort::default();
+        let password_hasher = password_hasher();
         let time = MockTimePort::default();
         let mut repository = MockUserPersistencePort::default();
 
@@ -295,11 +589,20 @@ mod tests {
             .times(1)
             .returning(|_| Err(DomainError::Internal("Find by e-mail failed".to_string())));
 
+        let mut transaction = MockTransactionPort::default();
+
+        transaction.expect_commit().never();
+        transaction.expect_rollback().times(1).returning(|| Ok(()));
+
+        let audit_log = MockAuditLogPort::default();
+
         let use_case = SignUpUseCase::new(
             Arc::new(id_generator),
             Arc::new(password_hasher),
             Arc::new(time),
-            Arc::new(repository),
+            Arc::new(unit_of_work(repository, transaction)),
+            Arc::new(audit_log),
+            Arc::new(metrics(SignUpOutcome::Internal, 1)),
         );
 
         let input = SignUpInput {
@@ -307,7 +610,8 @@ mod tests {
             last_name: "Doe".to_string(),
             email: "john.doe@mail.com".to_string(),
             password: "SuperSecret123".to_string(),
-            password_confirmation: "SuperSecret123".to_string(),
+            password_confirmation: Some("SuperSecret123".to_string()),
+            metadata: RequestMetadata::default(),
         };
 
         let result = use_case.perform(input).await;
@@ -323,7 +627,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn should_return_error_if_create_fails() {
+    async fn should_return_error_and_rollback_if_create_fails() {
         let mut id_generator = MockIdGeneratorPort::default();
 
         id_generator
@@ -336,11 +640,11 @@ mod tests {
         password_hasher
             .expect_hash_password()
             .times(1)
-            .returning(|_| "password_hash".to_string());
+            .returning(|_| Ok("password_hash".to_string()));
 
         let mut time = MockTimePort::default();
 
-        time.expect_utc_now().times(1).returning(|| 1_000_000);
+        time.expect_now().times(1).returning(|| NOW);
 
         let mut repository = MockUserPersistencePort::default();
 
@@ -354,11 +658,20 @@ mod tests {
             .times(1)
             .returning(|_| Err(DomainError::Internal("Create failed".to_string())));
 
+        let mut transaction = MockTransactionPort::default();
+
+        transaction.expect_commit().never();
+        transaction.expect_rollback().times(1).returning(|| Ok(()));
+
+        let audit_log = MockAuditLogPort::default();
+
         let use_case = SignUpUseCase::new(
             Arc::new(id_generator),
             Arc::new(password_hasher),
             Arc::new(time),
-            Arc::new(repository),
+            Arc::new(unit_of_work(repository, transaction)),
+            Arc::new(audit_log),
+            Arc::new(metrics(SignUpOutcome::Internal, 1)),
         );
 
         let input = SignUpInput {
@@ -366,7 +679,8 @@ mod tests {
             last_name: "Doe".to_string(),
             email: "john.doe@mail.com".to_string(),
             password: "SuperSecret123".to_string(),
-            password_confirmation: "SuperSecret123".to_string(),
+            password_confirmation: Some("SuperSecret123".to_string()),
+            metadata: RequestMetadata::default(),
         };
 
         let result = use_case.perform(input).await;
@@ -380,4 +694,418 @@ mod tests {
             DomainError::Internal("Something went wrong: Create failed".to_string())
         );
     }
+
+    #[tokio::test]
+    async fn should_hash_the_password_before_beginning_the_transaction() {
+        let mut sequence = Sequence::new();
+        let mut password_hasher = MockPasswordHasherPort::default();
+
+        password_hasher
+            .expect_hash_password()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok("password_hash".to_string()));
+
+        let mut repository = MockUserPersistencePort::default();
+
+        repository
+            .expect_find_by_email()
+            .times(1)
+            .returning(|_| Ok(Some(registered_user())));
+
+        let mut transaction = MockTransactionPort::default();
+        let repository: Arc<dyn UserPersistencePort> = Arc::new(repository);
+
+        transaction
+            .expect_users()
+            .returning(move || Arc::clone(&repository));
+        transaction.expect_rollback().times(1).returning(|| Ok(()));
+
+        let mut unit_of_work = MockUnitOfWorkPort::default();
+
+        unit_of_work
+            .expect_begin()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .return_once(move || Ok(Box::new(transaction)));
+
+        let use_case = SignUpUseCase::new(
+            Arc::new(MockIdGeneratorPort::default()),
+            Arc::new(password_hasher),
+            Arc::new(MockTimePort::default()),
+            Arc::new(unit_of_work),
+            Arc::new(MockAuditLogPort::default()),
+            Arc::new(metrics(SignUpOutcome::UserAlreadyExists, 1)),
+        );
+
+        let input = SignUpInput {
+            first_name: "John".to_string(),
+            last_name: "Doe".to_string(),
+            email: "john.doe@mail.com".to_string(),
+            password: "SuperSecret123".to_string(),
+            password_confirmation: Some("SuperSecret123".to_string()),
+            metadata: RequestMetadata::default(),
+        };
+
+        assert_eq!(
+            use_case.perform(input).await,
+            Err(DomainError::UserAlreadyExists)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_error_without_a_transaction_if_password_hashing_fails() {
+        let mut password_hasher = MockPasswordHasherPort::default();
+
+        password_hasher
+            .expect_hash_password()
+            .times(1)
+            .returning(|_| Err(PasswordHashError("RNG unavailable".to_string())));
+
+        let mut unit_of_work = MockUnitOfWorkPort::default();
+
+        unit_of_work.expect_begin().never();
+
+        let use_case = SignUpUseCase::new(
+            Arc::new(MockIdGeneratorPort::default()),
+            Arc::new(password_hasher),
+            Arc::new(MockTimePort::default()),
+            Arc::new(unit_of_work),
+            Arc::new(MockAuditLogPort::default()),
+            Arc::new(metrics(SignUpOutcome::Internal, 0)),
+        );
+
+        let input = SignUpInput {
+            first_name: "John".to_string(),
+            last_name: "Doe".to_string(),
+            email: "john.doe@mail.com".to_string(),
+            password: "SuperSecret123".to_string(),
+            password_confirmation: Some("SuperSecret123".to_string()),
+            metadata: RequestMetadata::default(),
+        };
+
+        let result = use_case.perform(input).await;
+
+        assert_eq!(
+            result,
+            Err(DomainError::Internal(
+                "Could not hash the password: RNG unavailable".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_create_reports_conflict() {
+        let mut id_generator = MockIdGeneratorPort::default();
+
+        id_generator
+            .expect_generate_id()
+            .times(1)
+            .returning(|| "generated_id".to_string());
+
+        let mut password_hasher = MockPasswordHasherPort::default();
+
+        password_hasher
+            .expect_hash_password()
+            .times(1)
+            .returning(|_| Ok("password_hash".to_string()));
+
+        let mut time = MockTimePort::default();
+
+        time.expect_now().times(1).returning(|| NOW);
+
+        let mut repository = MockUserPersistencePort::default();
+
+        repository
+            .expect_find_by_email()
+            .times(1)
+            .returning(|_| Ok(None));
+
+        repository
+            .expect_create()
+            .times(1)
+            .returning(|_| Err(DomainError::Conflict("email".to_string())));
+
+        let mut transaction = MockTransactionPort::default();
+
+        transaction.expect_commit().never();
+        transaction.expect_rollback().times(1).returning(|| Ok(()));
+
+        let audit_log = MockAuditLogPort::default();
+
+        let use_case = SignUpUseCase::new(
+            Arc::new(id_generator),
+            Arc::new(password_hasher),
+            Arc::new(time),
+            Arc::new(unit_of_work(repository, transaction)),
+            Arc::new(audit_log),
+            Arc::new(metrics(SignUpOutcome::UserAlreadyExists, 1)),
+        );
+
+        let input = SignUpInput {
+            first_name: "John".to_string(),
+            last_name: "Doe".to_string(),
+            email: "john.doe@mail.com".to_string(),
+            password: "SuperSecret123".to_string(),
+            password_confirmation: Some("SuperSecret123".to_string()),
+            metadata: RequestMetadata::default(),
+        };
+
+        let result = use_case.perform(input).await;
+
+        assert!(result.is_err());
+
+        let result_err = result.unwrap_err();
+
+        assert_eq!(result_err, DomainError::UserAlreadyExists);
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_commit_reports_conflict() {
+        let mut id_generator = MockIdGeneratorPort::default();
+
+        id_generator
+            .expect_generate_id()
+            .times(1)
+            .returning(|| "generated_id".to_string());
+
+        let mut password_hasher = MockPasswordHasherPort::default();
+
+        password_hasher
+            .expect_hash_password()
+            .times(1)
+            .returning(|_| Ok("password_hash".to_string()));
+
+        let mut time = MockTimePort::default();
+
+        time.expect_now().times(1).returning(|| NOW);
+
+        let mut repository = MockUserPersistencePort::default();
+
+        repository
+            .expect_find_by_email()
+            .times(1)
+            .returning(|_| Ok(None));
+
+        repository.expect_create().times(1).returning(Ok);
+
+        let mut transaction = MockTransactionPort::default();
+
+        transaction
+            .expect_commit()
+            .times(1)
+            .returning(|| Err(DomainError::Conflict("email".to_string())));
+
+        let mut audit_log = MockAuditLogPort::default();
+
+        audit_log.expect_record().never();
+
+        let use_case = SignUpUseCase::new(
+            Arc::new(id_generator),
+            Arc::new(password_hasher),
+            Arc::new(time),
+            Arc::new(unit_of_work(repository, transaction)),
+            Arc::new(audit_log),
+            Arc::new(metrics(SignUpOutcome::UserAlreadyExists, 1)),
+        );
+
+        let input = SignUpInput {
+            first_name: "John".to_string(),
+            last_name: "Doe".to_string(),
+            email: "john.doe@mail.com".to_string(),
+            password: "SuperSecret123".to_string(),
+            password_confirmation: Some("SuperSecret123".to_string()),
+            metadata: RequestMetadata::default(),
+        };
+
+        let result = use_case.perform(input).await;
+
+        assert!(result.is_err());
+
+        let result_err = result.unwrap_err();
+
+        assert_eq!(result_err, DomainError::UserAlreadyExists);
+    }
+
+    #[tokio::test]
+    async fn should_sign_up_even_if_audit_record_fails() {
+        let mut id_generator = MockIdGeneratorPort::default();
+
+        id_generator
+            .expect_generate_id()
+            .times(1)
+            .returning(|| "generated_id".to_string());
+
+        let mut password_hasher = MockPasswordHasherPort::default();
+
+        password_hasher
+            .expect_hash_password()
+            .times(1)
+            .returning(|_| Ok("password_hash".to_string()));
+
+        let mut time = MockTimePort::default();
+
+        time.expect_now().times(1).returning(|| NOW);
+
+        let mut repository = MockUserPersistencePort::default();
+
+        repository
+            .expect_find_by_email()
+            .times(1)
+            .returning(|_| Ok(None));
+
+        repository.expect_create().times(1).returning(Ok);
+
+        let mut transaction = MockTransactionPort::default();
+
+        transaction.expect_commit().times(1).returning(|| Ok(()));
+        transaction.expect_rollback().never();
+
+        let mut audit_log = MockAuditLogPort::default();
+
+        audit_log.expect_record().times(1).returning(|_| {
+            Err(AuditLogError::Storage(
+                "Audit log is not writable".to_string(),
+            ))
+        });
+
+        let use_case = SignUpUseCase::new(
+            Arc::new(id_generator),
+            Arc::new(password_hasher),
+            Arc::new(time),
+            Arc::new(unit_of_work(repository, transaction)),
+            Arc::new(audit_log),
+            Arc::new(metrics(SignUpOutcome::Success, 1)),
+        );
+
+        let input = SignUpInput {
+            first_name: "John".to_string(),
+            last_name: "Doe".to_string(),
+            email: "john.doe@mail.com".to_string(),
+            password: "SuperSecret123".to_string(),
+            password_confirmation: Some("SuperSecret123".to_string()),
+            metadata: RequestMetadata {
+                ip_address: Some("127.0.0.1".to_string()),
+                user_agent: Some("curl/8.0".to_string()),
+            },
+        };
+
+        let result = use_case.perform(input).await;
+
+        assert_eq!(result, Ok(registered_user()));
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_transaction_cannot_begin() {
+        let id_generator = MockIdGeneratorPort::default();
+        let password_hasher = password_hasher();
+        let time = MockTimePort::default();
+        let mut unit_of_work = MockUnitOfWorkPort::default();
+
+        unit_of_work
+            .expect_begin()
+            .times(1)
+            .returning(|| Err(DomainError::Internal("Begin failed".to_string())));
+
+        let audit_log = MockAuditLogPort::default();
+
+        let use_case = SignUpUseCase::new(
+            Arc::new(id_generator),
+            Arc::new(password_hasher),
+            Arc::new(time),
+            Arc::new(unit_of_work),
+            Arc::new(audit_log),
+            Arc::new(metrics(SignUpOutcome::Internal, 1)),
+        );
+
+        let input = SignUpInput {
+            first_name: "John".to_string(),
+            last_name: "Doe".to_string(),
+            email: "john.doe@mail.com".to_string(),
+            password: "SuperSecret123".to_string(),
+            password_confirmation: Some("SuperSecret123".to_string()),
+            metadata: RequestMetadata::default(),
+        };
+
+        let result = use_case.perform(input).await;
+
+        assert!(result.is_err());
+
+        let result_err = result.unwrap_err();
+
+        assert_eq!(
+            result_err,
+            DomainError::Internal("Something went wrong: Begin failed".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_error_if_commit_fails() {
+        let mut id_generator = MockIdGeneratorPort::default();
+
+        id_generator
+            .expect_generate_id()
+            .times(1)
+            .returning(|| "generated_id".to_string());
+
+        let mut password_hasher = MockPasswordHasherPort::default();
+
+        password_hasher
+            .expect_hash_password()
+            .times(1)
+            .returning(|_| Ok("password_hash".to_string()));
+
+        let mut time = MockTimePort::default();
+
+        time.expect_now().times(1).returning(|| NOW);
+
+        let mut repository = MockUserPersistencePort::default();
+
+        repository
+            .expect_find_by_email()
+            .times(1)
+            .returning(|_| Ok(None));
+
+        repository.expect_create().times(1).returning(Ok);
+
+        let mut transaction = MockTransactionPort::default();
+
+        transaction
+            .expect_commit()
+            .times(1)
+            .returning(|| Err(DomainError::Internal("Commit failed".to_string())));
+
+        let mut audit_log = MockAuditLogPort::default();
+
+        audit_log.expect_record().never();
+
+        let use_case = SignUpUseCase::new(
+            Arc::new(id_generator),
+            Arc::new(password_hasher),
+            Arc::new(time),
+            Arc::new(unit_of_work(repository, transaction)),
+            Arc::new(audit_log),
+            Arc::new(metrics(SignUpOutcome::Internal, 1)),
+        );
+
+        let input = SignUpInput {
+            first_name: "John".to_string(),
+            last_name: "Doe".to_string(),
+            email: "john.doe@mail.com".to_string(),
+            password: "SuperSecret123".to_string(),
+            password_confirmation: Some("SuperSecret123".to_string()),
+            metadata: RequestMetadata::default(),
+        };
+
+        let result = use_case.perform(input).await;
+
+        assert!(result.is_err());
+
+        let result_err = result.unwrap_err();
+
+        assert_eq!(
+            result_err,
+            DomainError::Internal("Something went wrong: Commit failed".to_string())
+        );
+    }
 }
@@ -0,0 +1,571 @@
+use std::sync::Arc;
+
+use axum::{Router, middleware, routing::get};
+use tokio::sync::watch;
+
+use crate::{
+    application::{
+        ports::adapters::{
+            audit_log::{AuditLogError, AuditLogPort},
+            env::EnvPort,
+            health_check::HealthCheckPort,
+            id_generator::IdGeneratorPort,
+            metrics::MetricsPort,
+            password_hasher::PasswordHasherPort,
+            rate_limit::RateLimitStorePort,
+            time::TimePort,
+        },
+        ports::use_cases::auth::sign_up::SignUpPort,
+        use_cases::auth::sign_up::SignUpUseCase,
+    },
+    composition::{
+        bootstrap::shutdown::{ShutdownCoordinator, ShutdownSignal},
+        config::{
+            app::{
+                ApiConfig, AppConfig, AuditLogStoreKind, ConfigError, IdGeneratorKind,
+                RateLimitStoreKind,
+            },
+            listeners::ListenerScope,
+            runtime::RuntimeConfig,
+        },
+    },
+    domain::repositories::unit_of_work::UnitOfWorkPort,
+    infrastructure::{
+        adapters::{
+            argon2_password_hasher::Argon2PasswordHasherAdapter,
+            audit_log::{json_lines::JsonLinesAuditLogAdapter, sqlite::SqliteAuditLogAdapter},
+            health::{
+                disk_space::DiskSpaceHealthCheck,
+                smtp::SmtpHealthCheck,
+                sqlite::{SqliteMigrationsHealthCheck, SqlitePingHealthCheck},
+            },
+            id_generator::{
+                random_uuid::RandomUuidAdapter, snowflake::SnowflakeAdapter, ulid::UlidAdapter,
+                uuid_v7::UuidV7Adapter,
+            },
+            prometheus_metrics::PrometheusMetricsAdapter,
+            rate_limit::{in_memory::InMemoryRateLimitStore, sqlite::SqliteRateLimitStore},
+            system_time::SystemTimeAdapter,
+        },
+        http::{
+            api_version::{ApiVersion, ApiVersionPolicy},
+            handlers::health::{HealthDetail, HealthState},
+            middlewares::{
+                api_version::{ApiVersionState, track_api_version},
+                cors::{CorsPolicy, cors},
+                metrics::track_http_metrics,
+                rate_limit::{RateLimitPolicy, RateLimiter},
+                request_id::assign_request_id,
+                trace::trace_requests,
+            },
+            routers::{
+                admin::admin_router, health::health_router, metrics::metrics_router,
+                openapi::openapi_router, v1::v1_router, v2::v2_router,
+            },
+        },
+        repositories::{measured::MeasuredUnitOfWork, sqlite::unit_of_work::SqliteUnitOfWork},
+    },
+};
+
+/// The routes served by each [`ListenerScope`].
+pub struct AppRouters {
+    pub public: Router,
+    pub internal: Router,
+}
+
+impl AppRouters {
+    pub fn for_scope(&self, scope: ListenerScope) -> Router {
+        match scope {
+            ListenerScope::Public => self.public.clone(),
+            ListenerScope::Internal => self.internal.clone(),
+        }
+    }
+}
+
+/// Assembles the application from its ports.
+///
+/// Every port defaults to its production adapter, built from the configuration, so tests only
+/// replace the ones they need to control and then drive the routers in-process.
+pub struct AppBuilder {
+    config: AppConfig,
+    runtime: Option<watch::Receiver<RuntimeConfig>>,
+    shutdown: Option<ShutdownSignal>,
+    id_generator: Option<Arc<dyn IdGeneratorPort>>,
+    request_id_generator: Option<Arc<dyn IdGeneratorPort>>,
+    password_hasher: Option<Arc<dyn PasswordHasherPort>>,
+    time: Option<Arc<dyn TimePort>>,
+    unit_of_work: Option<Arc<dyn UnitOfWorkPort>>,
+    audit_log: Option<Arc<dyn AuditLogPort>>,
+    rate_limit_store: Option<Arc<dyn RateLimitStorePort>>,
+    health_checks: Option<Vec<Arc<dyn HealthCheckPort>>>,
+    metrics: Option<Arc<PrometheusMetricsAdapter>>,
+}
+
+impl AppBuilder {
+    #[must_use]
+    pub const fn new(config: AppConfig) -> Self {
+        Self {
+            config,
+            runtime: None,
+            shutdown: None,
+            id_generator: None,
+            request_id_generator: None,
+            password_hasher: None,
+            time: None,
+            unit_of_work: None,
+            audit_log: None,
+            rate_limit_store: None,
+            health_checks: None,
+            metrics: None,
+        }
+    }
+
+    /// Resolves the configuration through `env`, which must already be loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConfigError`] listing every required key that is missing and every value that
+    /// cannot be parsed.
+    pub fn from_env(env: &impl EnvPort) -> Result<Self, ConfigError> {
+        Ok(Self::new(AppConfig::load(env)?))
+    }
+
+    /// Follows configuration reloads. Without it, the settings loaded at startup stay in effect.
+    #[must_use]
+    pub fn with_runtime_config(mut self, runtime: watch::Receiver<RuntimeConfig>) -> Self {
+        self.runtime = Some(runtime);
+        self
+    }
+
+    /// Lets readiness fail once shutdown begins. Without it, the application never reports it.
+    #[must_use]
+    pub fn with_shutdown_signal(mut self, shutdown: ShutdownSignal) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    /// The generator of user ids.
+    #[must_use]
+    pub fn with_id_generator(mut self, id_generator: Arc<dyn IdGeneratorPort>) -> Self {
+        self.id_generator = Some(id_generator);
+        self
+    }
+
+    /// The generator of the ids assigned to requests that arrive without one. It is kept apart
+    /// from the user ids, so the ids a test expects do not depend on how many requests it sent.
+    #[must_use]
+    pub fn with_request_id_generator(
+        mut self,
+        request_id_generator: Arc<dyn IdGeneratorPort>,
+    ) -> Self {
+        self.request_id_generator = Some(request_id_generator);
+        self
+    }
+
+    #[must_use]
+    pub fn with_password_hasher(mut self, password_hasher: Arc<dyn PasswordHasherPort>) -> Self {
+        self.password_hasher = Some(password_hasher);
+        self
+    }
+
+    /// The clock behind user timestamps, audit entries and rate limits.
+    #[must_use]
+    pub fn with_time(mut self, time: Arc<dyn TimePort>) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// The unit of work handing out the user repositories, in place of the `SQLite` database.
+    #[must_use]
+    pub fn with_unit_of_work(mut self, unit_of_work: Arc<dyn UnitOfWorkPort>) -> Self {
+        self.unit_of_work = Some(unit_of_work);
+        self
+    }
+
+    #[must_use]
+    pub fn with_audit_log(mut self, audit_log: Arc<dyn AuditLogPort>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    #[must_use]
+    pub fn with_rate_limit_store(mut self, rate_limit_store: Arc<dyn RateLimitStorePort>) -> Self {
+        self.rate_limit_store = Some(rate_limit_store);
+        self
+    }
+
+    /// The checks readiness aggregates, in place of the database, disk and SMTP ones.
+    #[must_use]
+    pub fn with_health_checks(mut self, health_checks: Vec<Arc<dyn HealthCheckPort>>) -> Self {
+        self.health_checks = Some(health_checks);
+        self
+    }
+
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<PrometheusMetricsAdapter>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Builds the routers of both listener scopes. The operator routes, `/metrics` and the admin
+    /// API, are only ever part of the internal router, and go unserved without an internal
+    /// listener.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the default audit log or `SQLite` rate limit store cannot be opened.
+    pub fn build(self) -> Result<AppRouters, Box<dyn std::error::Error>> {
+        let config = &self.config;
+
+        let runtime = self
+            .runtime
+            .unwrap_or_else(|| watch::channel(config.runtime()).1);
+        let time = self.time.unwrap_or_else(|| Arc::new(SystemTimeAdapter));
+        let metrics = self
+            .metrics
+            .unwrap_or_else(|| Arc::new(PrometheusMetricsAdapter::new()));
+        let id_generator = self
+            .id_generator
+            .unwrap_or_else(|| default_id_generator(config));
+        let request_id_generator = self
+            .request_id_generator
+            .unwrap_or_else(|| Arc::new(RandomUuidAdapter));
+
+        // Sign-up records to the audit log, so it is opened whether or not the admin API that
+        // reads it is enabled.
+        let audit_log: Arc<dyn AuditLogPort> = match self.audit_log {
+            Some(audit_log) => audit_log,
+            None => default_audit_log(config, time.clone())?,
+        };
+
+        let rate_limit_store: Arc<dyn RateLimitStorePort> = match self.rate_limit_store {
+            Some(rate_limit_store) => rate_limit_store,
+            None => match config.rate_limit.store {
+                RateLimitStoreKind::Memory => Arc::new(InMemoryRateLimitStore::new()),
+                RateLimitStoreKind::Sqlite => {
+                    Arc::new(SqliteRateLimitStore::open(&config.database.path)?)
+                }
+            },
+        };
+
+        let unit_of_work = self
+            .unit_of_work
+            .unwrap_or_else(|| Arc::new(SqliteUnitOfWork::new(config.database.path.clone())));
+
+        let sign_up = SignUpUseCase::new(
+            id_generator.clone(),
+            self.password_hasher
+                .unwrap_or_else(|| Arc::new(Argon2PasswordHasherAdapter)),
+            time.clone(),
+            Arc::new(MeasuredUnitOfWork::new(unit_of_work, metrics.clone())),
+            audit_log.clone(),
+            metrics.clone(),
+        );
+        let sign_up: Arc<dyn SignUpPort> = Arc::new(sign_up);
+        let sign_up_limiter = Arc::new(RateLimiter::new(
+            RateLimitPolicy::sign_up({
+                let runtime = runtime.clone();
+
+                move || runtime.borrow().sign_up_rate_limit_per_hour
+            }),
+            rate_limit_store,
+            time.clone(),
+            metrics.clone(),
+        ));
+
+        let health = Arc::new(HealthState::new(
+            self.health_checks
+                .unwrap_or_else(|| default_health_checks(config)),
+            config.health.check_timeout(),
+            config.health.cache_ttl(),
+            {
+                // A signal whose coordinator is already gone is never triggered.
+                let shutdown = self.shutdown.unwrap_or_else(|| {
+                    ShutdownCoordinator::new(config.server.shutdown_timeout()).signal()
+                });
+
+                move || shutdown.is_triggered()
+            },
+        ));
+
+        let mut operator = metrics_router(metrics.clone());
+
+        // Admin routes stay unmounted unless an operator explicitly configures a token for them.
+        if let Some(admin_token) = &config.auth.admin_api_token {
+            operator = operator.merge(admin_router(audit_log, admin_token.clone()));
+        }
+
+        let metrics: Arc<dyn MetricsPort> = metrics;
+
+        let public = Router::new()
+            .route("/", get(|| async { "Hello, world!" }))
+            .merge(health_router(health.clone(), HealthDetail::StatusOnly))
+            .merge(openapi_router())
+            .merge(api_routers(
+                &config.api,
+                &time,
+                &sign_up,
+                &sign_up_limiter,
+                &metrics,
+            ));
+
+        let internal = health_router(health, HealthDetail::Full).merge(operator);
+
+        let cors_policy = CorsPolicy::new(move |origin| runtime.borrow().allows_origin(origin));
+
+        // The request id is assigned outermost, so even CORS preflight responses carry one.
+        let with_middlewares = |router: Router| {
+            router
+                .layer(middleware::from_fn_with_state(
+                    metrics.clone(),
+                    track_http_metrics,
+                ))
+                .layer(middleware::from_fn(trace_requests))
+                .layer(middleware::from_fn_with_state(cors_policy.clone(), cors))
+                .layer(middleware::from_fn_with_state(
+                    request_id_generator.clone(),
+                    assign_request_id,
+                ))
+        };
+
+        Ok(AppRouters {
+            public: with_middlewares(public),
+            internal: with_middlewares(internal),
+        })
+    }
+}
+
+/// Every API version under its prefix. Once the operator deprecates them, clients from before
+/// versioning keep reaching version 1 at the old paths, told by the Deprecation and Sunset headers
+/// to move, until the sunset date.
+fn api_routers(
+    api: &ApiConfig,
+    time: &Arc<dyn TimePort>,
+    sign_up: &Arc<dyn SignUpPort>,
+    sign_up_limiter: &Arc<RateLimiter>,
+    metrics: &Arc<dyn MetricsPort>,
+) -> Router {
+    let mut router = Router::new();
+
+    for version in ApiVersion::ALL {
+        router = router.nest(
+            version.prefix(),
+            api_router(
+                version,
+                api.policy(version),
+                sign_up.clone(),
+                sign_up_limiter.clone(),
+                time.clone(),
+                metrics.clone(),
+            ),
+        );
+    }
+
+    if api.unversioned.deprecated_at.is_some() {
+        router = router.merge(mark_api_version(
+            v1_router(sign_up.clone(), sign_up_limiter.clone()),
+            ApiVersionState::unversioned(api.unversioned, time.clone(), metrics.clone()),
+        ));
+    }
+
+    router
+}
+
+/// The routes of one API version, marked with its policy. Every version shares the use cases and
+/// the sign-up quota, and only differs in its request and response mappers.
+fn api_router(
+    version: ApiVersion,
+    policy: ApiVersionPolicy,
+    sign_up: Arc<dyn SignUpPort>,
+    sign_up_limiter: Arc<RateLimiter>,
+    time: Arc<dyn TimePort>,
+    metrics: Arc<dyn MetricsPort>,
+) -> Router {
+    let router = match version {
+        ApiVersion::V1 => v1_router(sign_up, sign_up_limiter),
+        ApiVersion::V2 => v2_router(sign_up, sign_up_limiter),
+    };
+
+    mark_api_version(router, ApiVersionState::new(version, policy, time, metrics))
+}
+
+fn mark_api_version(router: Router, state: ApiVersionState) -> Router {
+    router.route_layer(middleware::from_fn_with_state(
+        Arc::new(state),
+        track_api_version,
+    ))
+}
+
+fn default_audit_log(
+    config: &AppConfig,
+    time: Arc<dyn TimePort>,
+) -> Result<Arc<dyn AuditLogPort>, AuditLogError> {
+    Ok(match config.logging.audit_log_store {
+        AuditLogStoreKind::File => Arc::new(JsonLinesAuditLogAdapter::open(
+            &config.logging.audit_log_path,
+            time,
+        )?),
+        AuditLogStoreKind::Sqlite => {
+            Arc::new(SqliteAuditLogAdapter::open(&config.database.path, time)?)
+        }
+    })
+}
+
+fn default_id_generator(config: &AppConfig) -> Arc<dyn IdGeneratorPort> {
+    match config.ids.generator {
+        IdGeneratorKind::UuidV4 => Arc::new(RandomUuidAdapter),
+        IdGeneratorKind::UuidV7 => Arc::new(UuidV7Adapter::new()),
+        IdGeneratorKind::Ulid => Arc::new(UlidAdapter::new()),
+        IdGeneratorKind::Snowflake => {
+            Arc::new(SnowflakeAdapter::new(config.ids.snowflake_worker_id))
+        }
+    }
+}
+
+fn default_health_checks(config: &AppConfig) -> Vec<Arc<dyn HealthCheckPort>> {
+    let mut checks: Vec<Arc<dyn HealthCheckPort>> = vec![
+        Arc::new(SqlitePingHealthCheck::new(config.database.path.clone())),
+        Arc::new(SqliteMigrationsHealthCheck::new(
+            config.database.path.clone(),
+        )),
+        Arc::new(DiskSpaceHealthCheck::new(
+            "database_disk",
+            &config.database.path,
+            config.health.min_free_disk_mb,
+        )),
+    ];
+
+    // Every sign-up writes to the audit log, so its disk is checked even without the admin API;
+    // a log kept in the database is covered by the database disk check.
+    if config.logging.audit_log_store == AuditLogStoreKind::File {
+        checks.push(Arc::new(DiskSpaceHealthCheck::new(
+            "audit_log_disk",
+            &config.logging.audit_log_path,
+            config.health.min_free_disk_mb,
+        )));
+    }
+
+    if let Some(smtp_host) = &config.mail.smtp_host {
+        checks.push(Arc::new(SmtpHealthCheck::new(
+            smtp_host.clone(),
+            config.mail.smtp_port,
+        )));
+    }
+
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        http::{Request, StatusCode, header::CONTENT_TYPE},
+    };
+    use tower::ServiceExt;
+
+    use crate::{
+        composition::bootstrap::app::AppBuilder,
+        domain::values::timestamp::Timestamp,
+        infrastructure::{
+            adapters::audit_log::json_lines::JsonLinesAuditLogAdapter,
+            repositories::in_memory::{
+                unit_of_work::InMemoryUnitOfWork, user::InMemoryUserRepository,
+            },
+        },
+        test_support::fakes::{FakeEnv, ManualClock, PlainPasswordHasher, SequentialIdGenerator},
+        test_support::test_app::TestApp,
+    };
+
+    async fn send(router: &Router, request: Request<Body>) -> (StatusCode, String) {
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    fn sign_up_request() -> Request<Body> {
+        Request::post("/v1/auth/sign-up")
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(
+                r#"{"first_name":"John","last_name":"Doe","email":"john.doe@mail.com","password":"SuperSecret123","password_confirmation":"SuperSecret123"}"#,
+            ))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_serve_sign_ups_in_process_through_injected_ports() {
+        let audit_log_path = std::env::temp_dir().join(format!(
+            "axum_tdd_api_app_builder_{}.log",
+            std::process::id()
+        ));
+
+        let _ = std::fs::remove_file(&audit_log_path);
+
+        let env = FakeEnv::new(&[("SERVER_HOST", "127.0.0.1"), ("SERVER_PORT", "8080")]);
+        let time = Arc::new(ManualClock::new(Timestamp::from_unix_seconds(1_000_000)));
+        let routers = AppBuilder::from_env(&env)
+            .unwrap()
+            .with_id_generator(Arc::new(SequentialIdGenerator::new("user")))
+            .with_password_hasher(Arc::new(PlainPasswordHasher))
+            .with_time(time.clone())
+            .with_unit_of_work(Arc::new(InMemoryUnitOfWork::new(
+                &InMemoryUserRepository::new(),
+            )))
+            .with_audit_log(Arc::new(
+                JsonLinesAuditLogAdapter::open(&audit_log_path, time).unwrap(),
+            ))
+            .with_health_checks(Vec::new())
+            .build()
+            .unwrap();
+
+        let (status, body) = send(&routers.public, sign_up_request()).await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(body.contains(r#""id":"user_1""#));
+        assert!(body.contains(r#""created_at":"1970-01-12T13:46:40.000Z""#));
+        assert!(!body.contains("SuperSecret123"));
+
+        let (status, body) = send(&routers.public, sign_up_request()).await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert!(body.contains(r#""status":409"#));
+
+        let (status, metrics) = send(
+            &routers.internal,
+            Request::get("/metrics").body(Body::empty()).unwrap(),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(metrics.contains("sign_ups_total{outcome=\"success\"} 1\n"));
+        assert!(metrics.contains("sign_ups_total{outcome=\"user_already_exists\"} 1\n"));
+
+        let _ = std::fs::remove_file(&audit_log_path);
+    }
+
+    #[tokio::test]
+    async fn should_never_serve_operator_routes_publicly() {
+        let app = TestApp::with_settings(&[("ADMIN_API_TOKEN", "admin-token")]);
+
+        for path in ["/metrics", "/admin/audit-log"] {
+            let _ = app
+                .client()
+                .get(path)
+                .bearer("admin-token")
+                .send()
+                .await
+                .assert_status(StatusCode::NOT_FOUND);
+            let _ = app
+                .internal_client()
+                .get(path)
+                .bearer("admin-token")
+                .send()
+                .await
+                .assert_status(StatusCode::OK);
+        }
+    }
+}
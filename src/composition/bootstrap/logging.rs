@@ -0,0 +1,80 @@
+use tokio::sync::watch;
+use tracing_subscriber::{
+    Registry, filter::LevelFilter, layer::SubscriberExt, reload, util::SubscriberInitExt,
+    util::TryInitError,
+};
+
+use crate::{
+    composition::bootstrap::telemetry::Telemetry,
+    composition::config::{
+        app::{LogFormat, LoggingConfig},
+        runtime::{LogLevel, RuntimeConfig},
+    },
+    infrastructure::logging::{
+        json_format::JsonEventFormat,
+        redaction::{RedactingJsonFields, RedactingTextFields},
+    },
+};
+
+pub type LogLevelHandle = reload::Handle<LevelFilter, Registry>;
+
+/// Installs the global subscriber, writing JSON lines or human-readable text to stdout depending
+/// on `config.format`.
+///
+/// Spans are also handed to `telemetry` for export. The returned handle changes the level filter
+/// afterwards.
+///
+/// # Errors
+///
+/// Returns an error if a global subscriber has already been installed.
+pub fn init_logging(
+    config: &LoggingConfig,
+    telemetry: &Telemetry,
+) -> Result<LogLevelHandle, TryInitError> {
+    let (filter, handle) = reload::Layer::new(level_filter(config.level));
+
+    let (json, text) = match config.format {
+        LogFormat::Json => (
+            Some(
+                tracing_subscriber::fmt::layer()
+                    .fmt_fields(RedactingJsonFields)
+                    .event_format(JsonEventFormat),
+            ),
+            None,
+        ),
+        LogFormat::Pretty => (
+            None,
+            Some(tracing_subscriber::fmt::layer().fmt_fields(RedactingTextFields)),
+        ),
+    };
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(json)
+        .with(text)
+        .with(telemetry.layer())
+        .try_init()?;
+
+    Ok(handle)
+}
+
+/// Applies every log level published by the configuration reloader until it is dropped.
+pub async fn follow_log_level(mut runtime: watch::Receiver<RuntimeConfig>, handle: LogLevelHandle) {
+    while runtime.changed().await.is_ok() {
+        let level = runtime.borrow_and_update().log_level;
+
+        if let Err(err) = handle.modify(|filter| *filter = level_filter(level)) {
+            tracing::error!(error = %err, "Could not change log level");
+        }
+    }
+}
+
+const fn level_filter(level: LogLevel) -> LevelFilter {
+    match level {
+        LogLevel::Trace => LevelFilter::TRACE,
+        LogLevel::Debug => LevelFilter::DEBUG,
+        LogLevel::Info => LevelFilter::INFO,
+        LogLevel::Warn => LevelFilter::WARN,
+        LogLevel::Error => LevelFilter::ERROR,
+    }
+}
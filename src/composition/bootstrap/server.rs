@@ -1,69 +1,387 @@
-use axum::{Router, routing::get};
-use tokio::net::TcpListener;
+use std::{io::Write, sync::Arc, time::Duration};
+
+use tokio::{net::TcpListener, sync::watch, task::JoinSet};
 
 use crate::{
     application::ports::adapters::env::{EnvError, EnvPort},
-    infrastructure::adapters::dotenvy::DotenvyAdapter,
+    composition::{
+        bootstrap::{
+            app::{AppBuilder, AppRouters},
+            logging::{follow_log_level, init_logging},
+            shutdown::ShutdownCoordinator,
+            telemetry::Telemetry,
+        },
+        config::{
+            app::AppConfig,
+            listeners::{ListenerConfig, ListenerScope},
+            reloader::ConfigReloader,
+        },
+    },
+    infrastructure::{
+        adapters::layered_env::{LayeredEnvAdapter, LayeredEnvOptions},
+        http::{
+            listeners::{BoundListener, ListenAddress, SystemdSockets, bind, unix_socket_paths},
+            peer::PeerAddr,
+            routers::https_redirect::https_redirect_router,
+            tls::{TlsListener, load_server_config, watch_certificates},
+        },
+        repositories::sqlite::user::SqliteUserRepository,
+    },
 };
 
+const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const CERTIFICATE_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+type TlsConfigReceiver = watch::Receiver<Arc<rustls::ServerConfig>>;
+
+struct ScopedListener {
+    scope: ListenerScope,
+    address: ListenAddress,
+    listener: BoundListener,
+}
+
+/// The plain HTTP listener sending every request to `https_port`, on which the public TLS
+/// listener is bound.
+struct HttpsRedirect {
+    listener: TcpListener,
+    https_port: u16,
+}
+
 pub struct Server {
-    env_adapter: Option<DotenvyAdapter>,
+    args: Vec<String>,
+    config: Option<AppConfig>,
+    reloader: Option<ConfigReloader>,
+    shutdown: Option<Arc<ShutdownCoordinator>>,
+    telemetry: Option<Telemetry>,
 }
 
 impl Server {
     #[must_use]
     pub const fn new() -> Self {
-        Self { env_adapter: None }
+        Self::with_args(Vec::new())
+    }
+
+    /// Creates a server whose configuration can be overridden by `--some-key value` flags.
+    #[must_use]
+    pub const fn with_args(args: Vec<String>) -> Self {
+        Self {
+            args,
+            config: None,
+            reloader: None,
+            shutdown: None,
+            telemetry: None,
+        }
     }
 
-    /// Starts the HTTP server and blocks until it shuts down.
+    /// Starts the HTTP server and blocks until it shuts down, which happens gracefully on `SIGINT`
+    /// or `SIGTERM`.
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The TCP listener cannot be bound
+    /// - The configuration is missing or invalid
+    /// - The OTLP exporter cannot be created, or is configured in a build without it
+    /// - The database cannot be opened or migrated
+    /// - The audit log configured for the admin routes cannot be opened
+    /// - The TLS certificate, key or client CA cannot be loaded
+    /// - A listener, or the HTTPS redirect one, cannot be bound
     /// - The server fails while serving requests
     pub async fn run(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        self.setup_env()?;
+        self.setup_config()?;
+        self.setup_logging()?;
+        self.setup_database()?;
+
+        let listeners = self.setup_listeners().await?;
+        let tls = self.setup_tls()?;
+        let redirect = self.setup_https_redirect(&listeners).await?;
+        let routers = self.setup_routers()?;
+
+        let shutdown = self.setup_shutdown()?;
+
+        for listener in &listeners {
+            tracing::info!(
+                scope = listener.scope.as_str(),
+                address = %listener.address,
+                "Listening"
+            );
+        }
+
+        if let Some(redirect) = &redirect {
+            tracing::info!(
+                address = %redirect.listener.local_addr()?,
+                https_port = redirect.https_port,
+                "Redirecting to HTTPS"
+            );
+        }
+
+        if !listeners
+            .iter()
+            .any(|listener| listener.scope == ListenerScope::Internal)
+        {
+            tracing::warn!(
+                "No internal listener is configured, /metrics and the admin API are not served"
+            );
+        }
+
+        tracing::info!(tls = tls.is_some(), "Server started");
 
-        let listener = self.setup_listener().await?;
-        let router = Self::setup_router();
+        Self::setup_axum(listeners, tls, redirect, &routers, &shutdown).await?;
 
-        println!("🚀 Server started at http://{}", listener.local_addr()?);
+        shutdown.finish().await;
 
-        Self::setup_axum(listener, router).await?;
+        tracing::info!("Server stopped");
 
         Ok(())
     }
 
-    async fn setup_listener(&self) -> Result<TcpListener, Box<dyn std::error::Error>> {
-        let env_adapter = self
-            .env_adapter
-            .as_ref()
-            .ok_or(EnvError::EnvNotInitialized)?;
+    async fn setup_listeners(&self) -> Result<Vec<ScopedListener>, Box<dyn std::error::Error>> {
+        let config = self.config.as_ref().ok_or(EnvError::EnvNotInitialized)?;
 
-        let server_host: String = env_adapter.get_env_var("SERVER_HOST")?;
-        let server_port: u16 = env_adapter.get_env_var("SERVER_PORT")?;
-        let server_address = format!("{server_host}:{server_port}");
+        let mut systemd = SystemdSockets::from_env();
+        let mut listeners = Vec::new();
 
-        Ok(TcpListener::bind(server_address).await?)
+        for ListenerConfig { scope, address } in config.server.effective_listeners() {
+            let listener = bind(&address, config.server.unix_socket_mode, &mut systemd).await?;
+
+            listeners.push(ScopedListener {
+                scope,
+                address,
+                listener,
+            });
+        }
+
+        Ok(listeners)
+    }
+
+    /// Loads the certificates and keeps them up to date, if TLS is configured.
+    fn setup_tls(&self) -> Result<Option<TlsConfigReceiver>, Box<dyn std::error::Error>> {
+        let config = self.config.as_ref().ok_or(EnvError::EnvNotInitialized)?;
+        let shutdown = self.shutdown.as_ref().ok_or(EnvError::EnvNotInitialized)?;
+
+        let Some(tls) = &config.tls else {
+            return Ok(None);
+        };
+
+        let (sender, receiver) = watch::channel(load_server_config(tls)?);
+        let watcher = tokio::spawn(watch_certificates(
+            tls.clone(),
+            sender,
+            CERTIFICATE_POLL_INTERVAL,
+        ));
+
+        shutdown.register("certificate reloader", async move { watcher.abort() });
+
+        Ok(Some(receiver))
     }
 
-    fn setup_router() -> Router {
-        Router::new().route("/", get(|| async { "Hello, world!" }))
+    /// Binds the HTTPS redirect listener, if TLS is configured with a redirect port, on the host
+    /// of the public TCP listener TLS is served on, whose port it redirects to.
+    async fn setup_https_redirect(
+        &self,
+        listeners: &[ScopedListener],
+    ) -> Result<Option<HttpsRedirect>, Box<dyn std::error::Error>> {
+        let config = self.config.as_ref().ok_or(EnvError::EnvNotInitialized)?;
+
+        let Some(redirect_port) = config.tls.as_ref().and_then(|tls| tls.redirect_port) else {
+            return Ok(None);
+        };
+
+        let https = listeners
+            .iter()
+            .find_map(|listener| match &listener.listener {
+                BoundListener::Tcp(tcp) if listener.scope == ListenerScope::Public => {
+                    tcp.local_addr().ok()
+                }
+                _ => None,
+            });
+
+        let Some(https) = https else {
+            tracing::warn!(
+                "No public TCP listener serves HTTPS, the HTTPS redirect listener is not started"
+            );
+
+            return Ok(None);
+        };
+
+        let listener = TcpListener::bind((https.ip(), redirect_port)).await?;
+
+        Ok(Some(HttpsRedirect {
+            listener,
+            https_port: https.port(),
+        }))
     }
 
-    async fn setup_axum(listener: TcpListener, router: Router) -> std::io::Result<()> {
-        axum::serve(listener, router).await
+    fn setup_routers(&self) -> Result<AppRouters, Box<dyn std::error::Error>> {
+        let config = self.config.as_ref().ok_or(EnvError::EnvNotInitialized)?;
+        let reloader = self.reloader.as_ref().ok_or(EnvError::EnvNotInitialized)?;
+        let shutdown = self.shutdown.as_ref().ok_or(EnvError::EnvNotInitialized)?;
+
+        AppBuilder::new(config.clone())
+            .with_runtime_config(reloader.subscribe())
+            .with_shutdown_signal(shutdown.signal())
+            .build()
     }
 
-    fn setup_env(&mut self) -> Result<(), EnvError> {
-        let mut adapter = DotenvyAdapter::new();
+    fn setup_logging(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let config = self.config.as_ref().ok_or(EnvError::EnvNotInitialized)?;
+        let reloader = self.reloader.as_ref().ok_or(EnvError::EnvNotInitialized)?;
+
+        let telemetry = Telemetry::init(&config.telemetry)?;
+        let handle = init_logging(&config.logging, &telemetry)?;
+
+        tokio::spawn(follow_log_level(reloader.subscribe(), handle));
+
+        self.telemetry = Some(telemetry);
+
+        Ok(())
+    }
+
+    /// Applies pending migrations before the server can report itself ready.
+    fn setup_database(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let config = self.config.as_ref().ok_or(EnvError::EnvNotInitialized)?;
+
+        SqliteUserRepository::open(&config.database.path)?;
+
+        Ok(())
+    }
+
+    /// Registers the subsystems to stop once the server has drained, in the order they must stop:
+    /// background jobs first, then the spans and log buffers they may still write to.
+    fn setup_shutdown(&mut self) -> Result<Arc<ShutdownCoordinator>, Box<dyn std::error::Error>> {
+        let shutdown = self.shutdown.clone().ok_or(EnvError::EnvNotInitialized)?;
+
+        if let Some(reloader) = self.reloader.take() {
+            let reloader_task = tokio::spawn(reloader.watch(CONFIG_POLL_INTERVAL));
+
+            shutdown.register("config reloader", async move { reloader_task.abort() });
+        }
+
+        if let Some(config) = &self.config {
+            // The same listeners setup_listeners binds, so every socket file it created is removed.
+            let socket_paths = unix_socket_paths(
+                config
+                    .server
+                    .effective_listeners()
+                    .iter()
+                    .map(|listener| &listener.address),
+            );
+
+            shutdown.register("unix sockets", async move {
+                for path in socket_paths {
+                    let _ = std::fs::remove_file(path);
+                }
+            });
+        }
+
+        if let Some(telemetry) = self.telemetry.take() {
+            shutdown.register("trace exporter", async move {
+                let _ = tokio::task::spawn_blocking(move || telemetry.shutdown()).await;
+            });
+        }
+
+        shutdown.register("log buffers", async {
+            let _ = std::io::stdout().flush();
+            let _ = std::io::stderr().flush();
+        });
+
+        tokio::spawn({
+            let shutdown = shutdown.clone();
+
+            async move { shutdown.trigger_on_os_signal().await }
+        });
+
+        Ok(shutdown)
+    }
+
+    /// Serves every listener until shutdown, with TLS on the public TCP ones if it is enabled.
+    /// Internal listeners are meant for trusted networks and sidecars, so they stay plain.
+    async fn setup_axum(
+        listeners: Vec<ScopedListener>,
+        tls: Option<TlsConfigReceiver>,
+        redirect: Option<HttpsRedirect>,
+        routers: &AppRouters,
+        shutdown: &ShutdownCoordinator,
+    ) -> std::io::Result<()> {
+        let mut servers = JoinSet::new();
+
+        if let Some(HttpsRedirect {
+            listener,
+            https_port,
+        }) = redirect
+        {
+            servers.spawn(
+                axum::serve(listener, https_redirect_router(https_port))
+                    .with_graceful_shutdown(shutdown.signal().wait())
+                    .into_future(),
+            );
+        }
+
+        for ScopedListener {
+            scope, listener, ..
+        } in listeners
+        {
+            // The peer address is what rate limits keyed by client IP count against.
+            let service = routers
+                .for_scope(scope)
+                .into_make_service_with_connect_info::<PeerAddr>();
+            let stopped = shutdown.signal().wait();
+
+            match (listener, &tls) {
+                (BoundListener::Tcp(listener), Some(tls)) if scope == ListenerScope::Public => {
+                    servers.spawn(
+                        axum::serve(TlsListener::new(listener, tls.clone())?, service)
+                            .with_graceful_shutdown(stopped)
+                            .into_future(),
+                    );
+                }
+                (BoundListener::Tcp(listener), _) => {
+                    servers.spawn(
+                        axum::serve(listener, service)
+                            .with_graceful_shutdown(stopped)
+                            .into_future(),
+                    );
+                }
+                #[cfg(unix)]
+                (BoundListener::Unix(listener), _) => {
+                    servers.spawn(
+                        axum::serve(listener, service)
+                            .with_graceful_shutdown(stopped)
+                            .into_future(),
+                    );
+                }
+            }
+        }
+
+        shutdown
+            .drain(async move {
+                while let Some(served) = servers.join_next().await {
+                    served.map_err(std::io::Error::other)??;
+                }
+
+                Ok(())
+            })
+            .await
+    }
+
+    fn setup_config(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let args = std::mem::take(&mut self.args);
+        let mut adapter = LayeredEnvAdapter::new(LayeredEnvOptions::from_process(args.clone()));
 
         adapter.load_env_file()?;
-        adapter.check_env_vars()?;
 
-        self.env_adapter = Some(adapter);
+        let config = AppConfig::load(&adapter)?;
+
+        // Dropping the adapter zeroizes every value it read, secrets included.
+        drop(adapter);
+
+        self.reloader = Some(ConfigReloader::new(
+            move || LayeredEnvOptions::from_process(args.clone()),
+            config.runtime(),
+        ));
+        self.shutdown = Some(Arc::new(ShutdownCoordinator::new(
+            config.server.shutdown_timeout(),
+        )));
+        self.config = Some(config);
 
         Ok(())
     }
@@ -0,0 +1,220 @@
+use std::{future::Future, pin::Pin, sync::Mutex, time::Duration};
+
+use tokio::sync::watch;
+
+type ShutdownHook = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Resolves once shutdown has been requested. Cheap to clone, so every subsystem can hold one.
+#[derive(Clone)]
+pub struct ShutdownSignal(watch::Receiver<bool>);
+
+impl ShutdownSignal {
+    pub async fn wait(mut self) {
+        // The sender lives as long as the coordinator; if it is gone, shutdown has begun anyway.
+        let _ = self.0.wait_for(|triggered| *triggered).await;
+    }
+
+    #[must_use]
+    pub fn is_triggered(&self) -> bool {
+        *self.0.borrow()
+    }
+}
+
+/// Orders the shutdown of the process.
+///
+/// Once triggered, the HTTP server stops accepting connections and gets `deadline` to finish its
+/// in-flight requests. The registered subsystems are then shut down one after the other, in the
+/// order they registered, each also bounded by `deadline`.
+pub struct ShutdownCoordinator {
+    deadline: Duration,
+    sender: watch::Sender<bool>,
+    hooks: Mutex<Vec<(&'static str, ShutdownHook)>>,
+}
+
+impl ShutdownCoordinator {
+    #[must_use]
+    pub fn new(deadline: Duration) -> Self {
+        Self {
+            deadline,
+            sender: watch::Sender::new(false),
+            hooks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Registers `hook` to run during shutdown, after the subsystems registered before it. The
+    /// hook is not polled until then.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a previous hook registration panicked while holding the lock.
+    pub fn register(&self, name: &'static str, hook: impl Future<Output = ()> + Send + 'static) {
+        self.hooks
+            .lock()
+            .expect("shutdown hooks are poisoned")
+            .push((name, Box::pin(hook)));
+    }
+
+    #[must_use]
+    pub fn signal(&self) -> ShutdownSignal {
+        ShutdownSignal(self.sender.subscribe())
+    }
+
+    pub fn trigger(&self) {
+        self.sender.send_replace(true);
+    }
+
+    /// Triggers shutdown on the first `SIGINT` or `SIGTERM`.
+    pub async fn trigger_on_os_signal(&self) {
+        wait_for_os_signal().await;
+
+        tracing::info!("Shutting down, draining in-flight requests");
+
+        self.trigger();
+    }
+
+    /// Waits for `server`, which must itself stop accepting connections once the
+    /// [`signal`](Self::signal) resolves, giving it at most `deadline` after shutdown was
+    /// triggered.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error `server` fails with.
+    pub async fn drain(
+        &self,
+        server: impl Future<Output = std::io::Result<()>>,
+    ) -> std::io::Result<()> {
+        tokio::pin!(server);
+
+        tokio::select! {
+            result = &mut server => return result,
+            () = self.signal().wait() => {}
+        }
+
+        if let Ok(result) = tokio::time::timeout(self.deadline, server).await {
+            return result;
+        }
+
+        tracing::warn!(
+            deadline_seconds = self.deadline.as_secs(),
+            "In-flight requests did not finish before the deadline, dropping them"
+        );
+
+        Ok(())
+    }
+
+    /// Shuts the registered subsystems down in registration order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a hook registration panicked while holding the lock.
+    pub async fn finish(&self) {
+        let hooks = std::mem::take(&mut *self.hooks.lock().expect("shutdown hooks are poisoned"));
+
+        for (name, hook) in hooks {
+            if tokio::time::timeout(self.deadline, hook).await.is_err() {
+                tracing::warn!(
+                    subsystem = name,
+                    deadline_seconds = self.deadline.as_secs(),
+                    "Could not shut down subsystem before the deadline, skipping it"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+async fn wait_for_os_signal() {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    let Ok(mut terminate) = signal(SignalKind::terminate()) else {
+        let _ = tokio::signal::ctrl_c().await;
+
+        return;
+    };
+
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => {}
+        _ = terminate.recv() => {}
+    }
+}
+
+#[cfg(not(unix))]
+async fn wait_for_os_signal() {
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use axum::{Router, routing::get};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    use crate::composition::bootstrap::shutdown::ShutdownCoordinator;
+
+    #[tokio::test]
+    async fn should_let_slow_request_complete_and_then_shut_subsystems_down_in_order() {
+        let coordinator = Arc::new(ShutdownCoordinator::new(Duration::from_secs(5)));
+        let shut_down = Arc::new(Mutex::new(Vec::new()));
+
+        for name in ["outbox relay", "background jobs", "log buffers"] {
+            let shut_down = shut_down.clone();
+
+            coordinator.register(name, async move {
+                shut_down.lock().unwrap().push(name);
+            });
+        }
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let router = Router::new().route(
+            "/slow",
+            get(|| async {
+                tokio::time::sleep(Duration::from_millis(300)).await;
+
+                "finished"
+            }),
+        );
+
+        let server = tokio::spawn({
+            let coordinator = coordinator.clone();
+
+            async move {
+                let serve = axum::serve(listener, router)
+                    .with_graceful_shutdown(coordinator.signal().wait());
+
+                coordinator.drain(serve.into_future()).await.unwrap();
+                coordinator.finish().await;
+            }
+        });
+
+        let mut stream = TcpStream::connect(address).await.unwrap();
+
+        stream
+            .write_all(b"GET /slow HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        coordinator.trigger();
+
+        let mut response = String::new();
+
+        stream.read_to_string(&mut response).await.unwrap();
+        server.await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("finished"));
+        assert!(TcpStream::connect(address).await.is_err());
+        assert_eq!(
+            *shut_down.lock().unwrap(),
+            vec!["outbox relay", "background jobs", "log buffers"]
+        );
+    }
+}
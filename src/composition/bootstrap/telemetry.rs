@@ -0,0 +1,189 @@
+use crate::composition::config::app::TelemetryConfig;
+
+#[derive(Debug)]
+pub enum TelemetryError {
+    Exporter(String),
+    FeatureDisabled,
+}
+
+impl std::fmt::Display for TelemetryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exporter(err) => write!(f, "Could not create the OTLP span exporter: {err}"),
+            Self::FeatureDisabled => write!(
+                f,
+                "OTLP_ENDPOINT is set but this build was compiled without the `otlp` feature"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TelemetryError {}
+
+/// Exports spans to the OTLP collector configured in [`TelemetryConfig`], if any.
+///
+/// Export is compiled in with the `otlp` cargo feature. Spans are batched in the background, so
+/// [`shutdown`](Self::shutdown) must run before the process exits to flush the last ones.
+#[cfg(feature = "otlp")]
+pub struct Telemetry {
+    provider: Option<opentelemetry_sdk::trace::SdkTracerProvider>,
+}
+
+#[cfg(feature = "otlp")]
+impl Telemetry {
+    /// Creates the exporter, or a no-op telemetry when no endpoint is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TelemetryError::Exporter`] if the exporter rejects the configured endpoint.
+    pub fn init(config: &TelemetryConfig) -> Result<Self, TelemetryError> {
+        use opentelemetry_otlp::{Protocol, SpanExporter, WithExportConfig};
+        use opentelemetry_sdk::{Resource, trace::SdkTracerProvider};
+
+        use crate::composition::config::app::OtlpProtocol;
+
+        let Some(endpoint) = &config.otlp_endpoint else {
+            return Ok(Self { provider: None });
+        };
+
+        let exporter = match config.otlp_protocol {
+            OtlpProtocol::Grpc => SpanExporter::builder()
+                .with_tonic()
+                .with_endpoint(endpoint)
+                .build(),
+            // Unlike the gRPC one, the HTTP exporter posts to the endpoint exactly as given.
+            OtlpProtocol::Http => SpanExporter::builder()
+                .with_http()
+                .with_protocol(Protocol::HttpBinary)
+                .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+                .build(),
+        }
+        .map_err(|err| TelemetryError::Exporter(err.to_string()))?;
+
+        let provider = SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_resource(
+                Resource::builder()
+                    .with_service_name(config.service_name.clone())
+                    .build(),
+            )
+            .build();
+
+        Ok(Self {
+            provider: Some(provider),
+        })
+    }
+
+    /// A layer turning `tracing` spans into exported OpenTelemetry spans, if export is enabled.
+    #[must_use]
+    pub fn layer<S>(
+        &self,
+    ) -> Option<tracing_opentelemetry::OpenTelemetryLayer<S, opentelemetry_sdk::trace::Tracer>>
+    where
+        S: tracing::Subscriber + for<'span> tracing_subscriber::registry::LookupSpan<'span>,
+    {
+        use opentelemetry::trace::TracerProvider;
+
+        self.provider.as_ref().map(|provider| {
+            tracing_opentelemetry::layer().with_tracer(provider.tracer(env!("CARGO_PKG_NAME")))
+        })
+    }
+
+    /// Flushes the spans still batched and stops exporting. Blocks until the exporter is done.
+    pub fn shutdown(&self) {
+        let Some(provider) = &self.provider else {
+            return;
+        };
+
+        if let Err(err) = provider.shutdown() {
+            tracing::warn!(error = %err, "Could not flush the last spans to the collector");
+        }
+    }
+}
+
+/// Builds without the `otlp` feature export nothing, and refuse a configured endpoint rather
+/// than silently ignoring it.
+#[cfg(not(feature = "otlp"))]
+pub struct Telemetry;
+
+#[cfg(not(feature = "otlp"))]
+impl Telemetry {
+    /// # Errors
+    ///
+    /// Returns a [`TelemetryError::FeatureDisabled`] if an OTLP endpoint is configured.
+    pub const fn init(config: &TelemetryConfig) -> Result<Self, TelemetryError> {
+        if config.otlp_endpoint.is_some() {
+            return Err(TelemetryError::FeatureDisabled);
+        }
+
+        Ok(Self)
+    }
+
+    #[must_use]
+    pub const fn layer(&self) -> Option<tracing_subscriber::layer::Identity> {
+        None
+    }
+
+    pub const fn shutdown(&self) {}
+}
+
+#[cfg(all(test, feature = "otlp"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::{Router, body::Bytes, extract::State, routing::post};
+    use tokio::net::TcpListener;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use crate::composition::{
+        bootstrap::telemetry::Telemetry,
+        config::app::{OtlpProtocol, TelemetryConfig},
+    };
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_export_spans_to_collector() {
+        let received = Arc::new(Mutex::new(Vec::<Bytes>::new()));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        // Stands in for an OTLP collector, keeping the protobuf bodies it is sent.
+        let collector = Router::new()
+            .route(
+                "/v1/traces",
+                post(
+                    |State(received): State<Arc<Mutex<Vec<Bytes>>>>, body: Bytes| async move {
+                        received.lock().unwrap().push(body);
+                    },
+                ),
+            )
+            .with_state(received.clone());
+
+        tokio::spawn(async move { axum::serve(listener, collector).await });
+
+        let telemetry = Telemetry::init(&TelemetryConfig {
+            otlp_endpoint: Some(format!("http://{address}")),
+            otlp_protocol: OtlpProtocol::Http,
+            service_name: "telemetry_test".to_string(),
+        })
+        .unwrap();
+        let subscriber = tracing_subscriber::registry().with(telemetry.layer());
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("sign_up").in_scope(|| tracing::info!("signing up"));
+        });
+
+        tokio::task::spawn_blocking(move || telemetry.shutdown())
+            .await
+            .unwrap();
+
+        let received = received.lock().unwrap();
+        let contains = |needle: &[u8]| {
+            received
+                .iter()
+                .any(|body| body.windows(needle.len()).any(|window| window == needle))
+        };
+
+        assert!(contains(b"sign_up"));
+        assert!(contains(b"telemetry_test"));
+    }
+}
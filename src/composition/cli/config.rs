@@ -0,0 +1,362 @@
+use std::{fmt::Write, process::ExitCode};
+
+use serde_json::{Map, Value, json};
+use zeroize::Zeroize;
+
+use crate::{
+    application::ports::adapters::env::{ConfigSource, EnvPort, ResolvedValue},
+    composition::config::{
+        app::AppConfig,
+        settings::{SETTINGS, Setting, SettingKind},
+    },
+    domain::values::secret::REDACTED,
+    infrastructure::adapters::layered_env::{LayeredEnvAdapter, LayeredEnvOptions},
+};
+
+const USAGE: &str = "Usage: axum_tdd_api config <check|show|schema> [--some-key value]...
+
+  check   Validate every setting and exit non-zero if any is missing or invalid
+  show    Print the effective value of every setting and the layer it comes from
+  schema  Print a JSON Schema describing every setting";
+
+/// Runs `config <subcommand>`, where `args` are the arguments following `config`. Flags after
+/// the subcommand override settings exactly as they do when starting the server.
+#[must_use]
+pub fn run(mut args: Vec<String>) -> ExitCode {
+    if args.is_empty() {
+        eprintln!("{USAGE}");
+
+        return ExitCode::from(2);
+    }
+
+    let subcommand = args.remove(0);
+
+    match subcommand.as_str() {
+        "check" => check(args),
+        "show" => show(args),
+        "schema" => {
+            println!("{:#}", schema());
+
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("Unknown config subcommand '{subcommand}'\n\n{USAGE}");
+
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn check(args: Vec<String>) -> ExitCode {
+    let Some(adapter) = load_sources(args) else {
+        return ExitCode::FAILURE;
+    };
+
+    match AppConfig::load(&adapter) {
+        Ok(_) => {
+            println!("✅ Configuration is valid");
+
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn show(args: Vec<String>) -> ExitCode {
+    let Some(adapter) = load_sources(args) else {
+        return ExitCode::FAILURE;
+    };
+
+    print!("{}", render_settings(&adapter));
+
+    match AppConfig::load(&adapter) {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("\n{err}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn load_sources(args: Vec<String>) -> Option<LayeredEnvAdapter> {
+    let mut adapter = LayeredEnvAdapter::new(LayeredEnvOptions::from_process(args));
+
+    match adapter.load_env_file() {
+        Ok(()) => Some(adapter),
+        Err(err) => {
+            eprintln!("Could not load configuration sources: {err}");
+
+            None
+        }
+    }
+}
+
+/// One line per declared setting, with the layer its value was resolved from.
+fn render_settings(env: &impl EnvPort) -> String {
+    let rows = SETTINGS
+        .iter()
+        .map(|setting| {
+            let (value, source) = match env.lookup(setting.key) {
+                Ok(Some(ResolvedValue { value, source })) => {
+                    (display_value(setting, value), source.to_string())
+                }
+                Ok(None) => setting.default.map_or_else(
+                    || ("<not set>".to_string(), String::new()),
+                    |default| {
+                        (
+                            display_value(setting, default.to_string()),
+                            ConfigSource::Default.to_string(),
+                        )
+                    },
+                ),
+                Err(err) => (format!("<unreadable: {err}>"), String::new()),
+            };
+
+            (setting.key, value, source)
+        })
+        .collect::<Vec<_>>();
+
+    let key_width = rows.iter().map(|(key, ..)| key.len()).max().unwrap_or(0);
+    let value_width = rows
+        .iter()
+        .map(|(_, value, _)| value.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    rows.iter()
+        .fold(String::new(), |mut output, (key, value, source)| {
+            let line = format!("{key:<key_width$}  {value:<value_width$}  {source}");
+            let _ = writeln!(output, "{}", line.trim_end());
+
+            output
+        })
+}
+
+fn display_value(setting: &Setting, mut value: String) -> String {
+    if setting.secret {
+        value.zeroize();
+
+        return REDACTED.to_string();
+    }
+
+    if value.is_empty() {
+        "\"\"".to_string()
+    } else {
+        value
+    }
+}
+
+/// Describes every declared setting as a JSON Schema (draft 2020-12) object, keyed by the setting
+/// name used in the environment.
+#[must_use]
+pub fn schema() -> Value {
+    let properties = SETTINGS
+        .iter()
+        .map(|setting| (setting.key.to_string(), setting_schema(setting)))
+        .collect::<Map<_, _>>();
+
+    let required = SETTINGS
+        .iter()
+        .filter(|setting| setting.required)
+        .map(|setting| setting.key)
+        .collect::<Vec<_>>();
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "axum_tdd_api configuration",
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": true,
+    })
+}
+
+fn setting_schema(setting: &Setting) -> Value {
+    let mut schema = match setting.kind {
+        SettingKind::String => json!({ "type": "string" }),
+        SettingKind::Port => json!({ "type": "string", "pattern": range_pattern(u16::MAX.into()) }),
+        SettingKind::Integer => {
+            json!({ "type": "string", "pattern": range_pattern(u32::MAX.into()) })
+        }
+        SettingKind::BoundedInteger { max } => {
+            json!({ "type": "string", "pattern": range_pattern(max.into()) })
+        }
+        SettingKind::OriginList => json!({
+            "type": "string",
+            "pattern": list_pattern(r"\*|https?://[^,/\s]+/*"),
+        }),
+        SettingKind::ListenerList => json!({
+            "type": "string",
+            "pattern": list_pattern(
+                r"(public|internal)\s*=\s*(tcp://[^,]*:[^,]*|unix://[^,]+|systemd://[^,]+)"
+            ),
+        }),
+        SettingKind::FileMode => json!({ "type": "string", "pattern": "^[0-7]{3,4}$" }),
+        SettingKind::Timestamp => json!({
+            "type": "string",
+            "pattern": "^\\d{4}-\\d{2}-\\d{2}(T\\d{2}:\\d{2}:\\d{2}(\\.\\d{3})?Z)?$",
+        }),
+        SettingKind::Enum(values) => json!({ "type": "string", "enum": values }),
+    };
+
+    schema["description"] = json!(setting.description);
+    schema["x-section"] = json!(setting.section);
+    schema["x-reloadable"] = json!(setting.reloadable);
+
+    if setting.secret {
+        schema["writeOnly"] = json!(true);
+    }
+
+    if let Some(default) = setting.default {
+        schema["default"] = json!(default);
+    }
+
+    schema
+}
+
+/// Matches the whole numbers from 0 to `max`, leading zeros included, as settings are parsed.
+/// Every value is written as a string, since that is what the environment and secret files hold.
+fn range_pattern(max: u64) -> String {
+    let digits = max.to_string().into_bytes();
+    let mut alternatives = Vec::new();
+
+    if digits.len() > 1 {
+        alternatives.push(format!("\\d{{1,{}}}", digits.len() - 1));
+    }
+
+    // Numbers as long as `max`, equal to it up to digit `i` and lower at that digit.
+    for (i, digit) in digits.iter().enumerate() {
+        let prefix = String::from_utf8_lossy(&digits[..i]);
+        let rest = match digits.len() - i - 1 {
+            0 => String::new(),
+            1 => "\\d".to_string(),
+            rest => format!("\\d{{{rest}}}"),
+        };
+
+        match digit {
+            b'0' => {}
+            b'1' => alternatives.push(format!("{prefix}0{rest}")),
+            digit => alternatives.push(format!("{prefix}[0-{}]{rest}", char::from(digit - 1))),
+        }
+    }
+
+    alternatives.push(max.to_string());
+
+    format!("^({})$", alternatives.join("|"))
+}
+
+/// Matches a comma-separated list of `entry`, where entries may be blank and surrounded by spaces.
+fn list_pattern(entry: &str) -> String {
+    let entry = format!("\\s*({entry})?\\s*");
+
+    format!("^{entry}(,{entry})*$")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        application::ports::adapters::env::ConfigSource,
+        composition::cli::config::{render_settings, schema},
+        test_support::fakes::FakeEnv,
+    };
+
+    #[test]
+    fn should_show_each_value_with_its_source_and_redact_secrets() {
+        let env = FakeEnv::new(&[])
+            .with_source(
+                "SERVER_HOST",
+                "127.0.0.1",
+                ConfigSource::ConfigFile("config.toml".to_string()),
+            )
+            .with_source("SERVER_PORT", "8080", ConfigSource::CommandLine)
+            .with_source(
+                "ADMIN_API_TOKEN",
+                "SuperSecret123",
+                ConfigSource::SecretFile("/run/secrets/admin".to_string()),
+            );
+
+        let output = render_settings(&env);
+        let line = |key: &str| {
+            output
+                .lines()
+                .find(|line| line.starts_with(&format!("{key} ")))
+                .unwrap()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        assert!(!output.contains("SuperSecret123"));
+        assert_eq!(line("SERVER_HOST"), "SERVER_HOST 127.0.0.1 config.toml");
+        assert_eq!(line("SERVER_PORT"), "SERVER_PORT 8080 command line");
+        assert_eq!(
+            line("ADMIN_API_TOKEN"),
+            "ADMIN_API_TOKEN [REDACTED] secret file /run/secrets/admin"
+        );
+        assert_eq!(
+            line("DATABASE_PATH"),
+            "DATABASE_PATH axum_tdd_api.db default"
+        );
+        assert_eq!(line("MAIL_FROM"), "MAIL_FROM <not set>");
+    }
+
+    #[test]
+    fn should_describe_every_setting_in_schema() {
+        let schema = schema();
+
+        assert_eq!(schema["required"], json!(["SERVER_HOST", "SERVER_PORT"]));
+        assert_eq!(
+            schema["properties"]["MAIL_SMTP_PORT"]["default"],
+            json!("587")
+        );
+        assert_eq!(
+            schema["properties"]["LOG_FORMAT"]["enum"],
+            json!(["pretty", "json"])
+        );
+        assert_eq!(
+            schema["properties"]["ADMIN_API_TOKEN"]["writeOnly"],
+            json!(true)
+        );
+        assert_eq!(
+            schema["properties"]["CORS_ALLOWED_ORIGINS"]["x-reloadable"],
+            json!(true)
+        );
+    }
+
+    #[test]
+    fn should_describe_numbers_and_lists_as_patterned_strings() {
+        let schema = schema();
+        let property = |key: &str| &schema["properties"][key];
+
+        for key in [
+            "SERVER_PORT",
+            "MAIL_SMTP_PORT",
+            "IDS_SNOWFLAKE_WORKER_ID",
+            "CORS_ALLOWED_ORIGINS",
+            "SERVER_LISTENERS",
+        ] {
+            assert_eq!(property(key)["type"], "string", "{key}");
+        }
+
+        assert_eq!(
+            property("SERVER_PORT")["pattern"],
+            r"^(\d{1,4}|[0-5]\d{4}|6[0-4]\d{3}|65[0-4]\d{2}|655[0-2]\d|6553[0-4]|65535)$"
+        );
+        assert_eq!(
+            property("IDS_SNOWFLAKE_WORKER_ID")["pattern"],
+            r"^(\d{1,3}|0\d{3}|10[0-1]\d|102[0-2]|1023)$"
+        );
+        assert_eq!(property("MAIL_SMTP_PORT")["default"], "587");
+        assert_eq!(
+            property("CORS_ALLOWED_ORIGINS")["pattern"],
+            r"^\s*(\*|https?://[^,/\s]+/*)?\s*(,\s*(\*|https?://[^,/\s]+/*)?\s*)*$"
+        );
+    }
+}
@@ -0,0 +1,11 @@
+use std::process::ExitCode;
+
+use crate::infrastructure::http::openapi::spec_json;
+
+/// Runs `openapi`, printing the spec of the public API, as committed in `openapi.json`.
+#[must_use]
+pub fn run() -> ExitCode {
+    println!("{}", spec_json());
+
+    ExitCode::SUCCESS
+}
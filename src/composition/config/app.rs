@@ -0,0 +1,641 @@
+use std::str::FromStr;
+
+use zeroize::Zeroize;
+
+use crate::{
+    application::ports::adapters::env::{EnvPort, ResolvedValue},
+    composition::config::{
+        listeners::{FileMode, ListenerConfig, ListenerList, ListenerScope},
+        runtime::{AllowedOrigins, LogLevel, RuntimeConfig},
+        settings::{SETTINGS, Setting, find_setting},
+    },
+    domain::values::secret::{REDACTED, Secret},
+    infrastructure::{
+        adapters::id_generator::snowflake::MAX_WORKER_ID,
+        http::{
+            api_version::{ApiVersion, ApiVersionPolicy},
+            date_time::DateTime,
+            listeners::ListenAddress,
+            tls::TlsConfig,
+        },
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigIssue {
+    Missing {
+        key: &'static str,
+    },
+    Invalid {
+        key: &'static str,
+        value: String,
+        expected: String,
+    },
+    Unreadable {
+        key: &'static str,
+        reason: String,
+    },
+}
+
+impl std::fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { key } => write!(f, "{key} is not set"),
+            Self::Invalid {
+                key,
+                value,
+                expected,
+            } => write!(f, "{key} is '{value}' but must be {expected}"),
+            Self::Unreadable { key, reason } => write!(f, "{key} could not be read: {reason}"),
+        }
+    }
+}
+
+/// Every problem found while building an [`AppConfig`], reported together so a deploy can be
+/// fixed in one go.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConfigError {
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid configuration:")?;
+
+        for issue in &self.issues {
+            write!(f, "\n  - {issue}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    #[default]
+    Pretty,
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OtlpProtocol {
+    #[default]
+    Grpc,
+    Http,
+}
+
+impl FromStr for OtlpProtocol {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "grpc" => Ok(Self::Grpc),
+            "http" => Ok(Self::Http),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RateLimitStoreKind {
+    #[default]
+    Memory,
+    Sqlite,
+}
+
+impl FromStr for RateLimitStoreKind {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "memory" => Ok(Self::Memory),
+            "sqlite" => Ok(Self::Sqlite),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AuditLogStoreKind {
+    #[default]
+    File,
+    Sqlite,
+}
+
+impl FromStr for AuditLogStoreKind {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "file" => Ok(Self::File),
+            "sqlite" => Ok(Self::Sqlite),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IdGeneratorKind {
+    UuidV4,
+    #[default]
+    UuidV7,
+    Ulid,
+    Snowflake,
+}
+
+impl FromStr for IdGeneratorKind {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "uuid_v4" => Ok(Self::UuidV4),
+            "uuid_v7" => Ok(Self::UuidV7),
+            "ulid" => Ok(Self::Ulid),
+            "snowflake" => Ok(Self::Snowflake),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A Snowflake worker id, which must fit in the 10 bits the id reserves for it.
+#[derive(Debug, Default)]
+struct WorkerId(u16);
+
+impl FromStr for WorkerId {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.parse() {
+            Ok(worker_id) if worker_id <= MAX_WORKER_ID => Ok(Self(worker_id)),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerConfig {
+    pub host: String,
+    pub port: u16,
+    pub shutdown_timeout_seconds: u32,
+    pub listeners: Vec<ListenerConfig>,
+    pub unix_socket_mode: u32,
+}
+
+impl ServerConfig {
+    #[must_use]
+    pub fn address(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+
+    /// Every listener to open: the declared ones, preceded by `host:port` unless a public one is
+    /// declared.
+    #[must_use]
+    pub fn effective_listeners(&self) -> Vec<ListenerConfig> {
+        let has_public = self
+            .listeners
+            .iter()
+            .any(|listener| listener.scope == ListenerScope::Public);
+
+        let primary = (!has_public).then(|| ListenerConfig {
+            scope: ListenerScope::Public,
+            address: ListenAddress::Tcp(self.address()),
+        });
+
+        primary
+            .into_iter()
+            .chain(self.listeners.iter().cloned())
+            .collect()
+    }
+
+    #[must_use]
+    pub fn shutdown_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(u64::from(self.shutdown_timeout_seconds))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseConfig {
+    pub path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthConfig {
+    pub admin_api_token: Option<Secret<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MailConfig {
+    pub smtp_host: Option<String>,
+    pub smtp_port: u16,
+    pub smtp_password: Option<Secret<String>>,
+    pub from_address: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorsConfig {
+    pub allowed_origins: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    pub sign_up_per_hour: u32,
+    pub store: RateLimitStoreKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdsConfig {
+    pub generator: IdGeneratorKind,
+    pub snowflake_worker_id: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiConfig {
+    pub v1: ApiVersionPolicy,
+    pub v2: ApiVersionPolicy,
+    /// Version 1 as served at the paths from before versioning, such as `/auth/sign-up`.
+    pub unversioned: ApiVersionPolicy,
+}
+
+impl ApiConfig {
+    #[must_use]
+    pub const fn policy(&self, version: ApiVersion) -> ApiVersionPolicy {
+        match version {
+            ApiVersion::V1 => self.v1,
+            ApiVersion::V2 => self.v2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthConfig {
+    pub check_timeout_ms: u32,
+    pub cache_ttl_ms: u32,
+    pub min_free_disk_mb: u32,
+}
+
+impl HealthConfig {
+    #[must_use]
+    pub fn check_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(u64::from(self.check_timeout_ms))
+    }
+
+    #[must_use]
+    pub fn cache_ttl(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(u64::from(self.cache_ttl_ms))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoggingConfig {
+    pub level: LogLevel,
+    pub format: LogFormat,
+    pub audit_log_path: String,
+    pub audit_log_store: AuditLogStoreKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelemetryConfig {
+    pub otlp_endpoint: Option<String>,
+    pub otlp_protocol: OtlpProtocol,
+    pub service_name: String,
+}
+
+/// The whole application configuration, resolved once at startup from the keys declared in
+/// [`SETTINGS`](crate::composition::config::settings::SETTINGS).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppConfig {
+    pub server: ServerConfig,
+    /// `None` serves plain HTTP.
+    pub tls: Option<TlsConfig>,
+    pub database: DatabaseConfig,
+    pub auth: AuthConfig,
+    pub mail: MailConfig,
+    pub logging: LoggingConfig,
+    pub cors: CorsConfig,
+    pub rate_limit: RateLimitConfig,
+    pub ids: IdsConfig,
+    pub api: ApiConfig,
+    pub health: HealthConfig,
+    pub telemetry: TelemetryConfig,
+}
+
+impl AppConfig {
+    /// Builds the configuration from `env`, falling back to declared defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConfigError`] listing every required key that is missing and every value that
+    /// cannot be parsed.
+    pub fn load(env: &impl EnvPort) -> Result<Self, ConfigError> {
+        let mut reader = ConfigReader {
+            env,
+            issues: Vec::new(),
+        };
+
+        // The catalog decides what is required, so a required setting is reported missing however
+        // it is read below.
+        for setting in SETTINGS.iter().filter(|setting| setting.required) {
+            reader.require(setting);
+        }
+
+        let config = Self {
+            server: ServerConfig {
+                host: reader.value("SERVER_HOST"),
+                port: reader.value("SERVER_PORT"),
+                shutdown_timeout_seconds: reader.value("SERVER_SHUTDOWN_TIMEOUT_SECONDS"),
+                listeners: reader.value::<ListenerList>("SERVER_LISTENERS").0,
+                unix_socket_mode: reader.value::<FileMode>("SERVER_UNIX_SOCKET_MODE").0,
+            },
+            tls: Self::tls(&mut reader),
+            database: DatabaseConfig {
+                path: reader.value("DATABASE_PATH"),
+            },
+            auth: AuthConfig {
+                admin_api_token: reader.optional("ADMIN_API_TOKEN"),
+            },
+            mail: MailConfig {
+                smtp_host: reader.optional("MAIL_SMTP_HOST"),
+                smtp_port: reader.value("MAIL_SMTP_PORT"),
+                smtp_password: reader.optional("MAIL_SMTP_PASSWORD"),
+                from_address: reader.optional("MAIL_FROM"),
+            },
+            logging: LoggingConfig {
+                level: reader.value("LOG_LEVEL"),
+                format: reader.value("LOG_FORMAT"),
+                audit_log_path: reader.value("AUDIT_LOG_PATH"),
+                audit_log_store: reader.value("AUDIT_LOG_STORE"),
+            },
+            cors: CorsConfig {
+                allowed_origins: reader.value::<AllowedOrigins>("CORS_ALLOWED_ORIGINS").0,
+            },
+            rate_limit: RateLimitConfig {
+                sign_up_per_hour: reader.value("RATE_LIMIT_SIGN_UP_PER_HOUR"),
+                store: reader.value("RATE_LIMIT_STORE"),
+            },
+            ids: IdsConfig {
+                generator: reader.value("IDS_GENERATOR"),
+                snowflake_worker_id: reader.value::<WorkerId>("IDS_SNOWFLAKE_WORKER_ID").0,
+            },
+            api: ApiConfig {
+                v1: ApiVersionPolicy {
+                    deprecated_at: reader
+                        .optional("API_V1_DEPRECATED_AT")
+                        .map(|DateTime(at)| at),
+                    sunset_at: reader.optional("API_V1_SUNSET_AT").map(|DateTime(at)| at),
+                },
+                v2: ApiVersionPolicy {
+                    deprecated_at: reader
+                        .optional("API_V2_DEPRECATED_AT")
+                        .map(|DateTime(at)| at),
+                    sunset_at: reader.optional("API_V2_SUNSET_AT").map(|DateTime(at)| at),
+                },
+                unversioned: Self::unversioned(&mut reader),
+            },
+            health: HealthConfig {
+                check_timeout_ms: reader.value("HEALTH_CHECK_TIMEOUT_MS"),
+                cache_ttl_ms: reader.value("HEALTH_CACHE_TTL_MS"),
+                min_free_disk_mb: reader.value("HEALTH_MIN_FREE_DISK_MB"),
+            },
+            telemetry: TelemetryConfig {
+                otlp_endpoint: reader.optional("OTLP_ENDPOINT"),
+                otlp_protocol: reader.value("OTLP_PROTOCOL"),
+                service_name: reader.value("OTLP_SERVICE_NAME"),
+            },
+        };
+
+        if reader.issues.is_empty() {
+            Ok(config)
+        } else {
+            Err(ConfigError {
+                issues: reader.issues,
+            })
+        }
+    }
+
+    /// TLS is enabled by setting both the certificate and its key; setting only one of them is
+    /// reported as the other one missing.
+    fn tls<E: EnvPort>(reader: &mut ConfigReader<'_, E>) -> Option<TlsConfig> {
+        let cert_path = reader.optional("TLS_CERT_PATH");
+        let key_path = reader.optional("TLS_KEY_PATH");
+
+        match (cert_path, key_path) {
+            (Some(cert_path), Some(key_path)) => Some(TlsConfig {
+                cert_path,
+                key_path,
+                client_ca_path: reader.optional("TLS_CLIENT_CA_PATH"),
+                client_auth: reader.value("TLS_CLIENT_AUTH"),
+                redirect_port: reader.optional("TLS_REDIRECT_PORT"),
+            }),
+            (Some(_), None) => {
+                reader.issues.push(ConfigIssue::Missing {
+                    key: "TLS_KEY_PATH",
+                });
+
+                None
+            }
+            (None, Some(_)) => {
+                reader.issues.push(ConfigIssue::Missing {
+                    key: "TLS_CERT_PATH",
+                });
+
+                None
+            }
+            (None, None) => None,
+        }
+    }
+
+    /// The paths from before versioning are only served once they are deprecated, so a sunset
+    /// without a deprecation date is reported as the deprecation date missing.
+    fn unversioned<E: EnvPort>(reader: &mut ConfigReader<'_, E>) -> ApiVersionPolicy {
+        let deprecated_at = reader
+            .optional("API_UNVERSIONED_DEPRECATED_AT")
+            .map(|DateTime(at)| at);
+        let sunset_at = reader
+            .optional("API_UNVERSIONED_SUNSET_AT")
+            .map(|DateTime(at)| at);
+
+        if sunset_at.is_some() && deprecated_at.is_none() {
+            reader.issues.push(ConfigIssue::Missing {
+                key: "API_UNVERSIONED_DEPRECATED_AT",
+            });
+        }
+
+        ApiVersionPolicy {
+            deprecated_at,
+            sunset_at,
+        }
+    }
+
+    /// The subset of this configuration that can be reloaded without restarting.
+    #[must_use]
+    pub fn runtime(&self) -> RuntimeConfig {
+        RuntimeConfig {
+            log_level: self.logging.level,
+            cors_allowed_origins: self.cors.allowed_origins.clone(),
+            sign_up_rate_limit_per_hour: self.rate_limit.sign_up_per_hour,
+        }
+    }
+}
+
+enum Lookup {
+    Found(String),
+    Absent,
+    Failed,
+}
+
+/// Reads settings one by one, collecting issues instead of stopping at the first one. Values that
+/// could not be resolved are replaced by their type's default, which is never handed out because
+/// [`AppConfig::load`] fails whenever an issue was collected.
+struct ConfigReader<'a, E: EnvPort> {
+    env: &'a E,
+    issues: Vec<ConfigIssue>,
+}
+
+impl<E: EnvPort> ConfigReader<'_, E> {
+    /// Reports `setting` as missing if it is neither set nor defaulted. Unreadable values are left
+    /// for the read that follows to report.
+    fn require(&mut self, setting: &Setting) {
+        if matches!(self.env.lookup(setting.key), Ok(None)) && setting.default.is_none() {
+            self.issues.push(ConfigIssue::Missing { key: setting.key });
+        }
+    }
+
+    fn value<T: FromStr + Default>(&mut self, key: &'static str) -> T {
+        match self.lookup(key) {
+            Lookup::Found(value) => self.parse(key, value).unwrap_or_default(),
+            Lookup::Absent => {
+                let missing = ConfigIssue::Missing { key };
+
+                if !self.issues.contains(&missing) {
+                    self.issues.push(missing);
+                }
+
+                T::default()
+            }
+            Lookup::Failed => T::default(),
+        }
+    }
+
+    fn optional<T: FromStr>(&mut self, key: &'static str) -> Option<T> {
+        match self.lookup(key) {
+            Lookup::Found(value) => self.parse(key, value),
+            Lookup::Absent | Lookup::Failed => None,
+        }
+    }
+
+    fn lookup(&mut self, key: &'static str) -> Lookup {
+        match self.env.lookup(key) {
+            Ok(Some(ResolvedValue { value, .. })) => Lookup::Found(value),
+            Ok(None) => find_setting(key)
+                .and_then(|setting| setting.default)
+                .map_or(Lookup::Absent, |default| Lookup::Found(default.to_string())),
+            Err(err) => {
+                self.issues.push(ConfigIssue::Unreadable {
+                    key,
+                    reason: err.to_string(),
+                });
+
+                Lookup::Failed
+            }
+        }
+    }
+
+    fn parse<T: FromStr>(&mut self, key: &'static str, mut value: String) -> Option<T> {
+        let setting = find_setting(key);
+        let is_secret = setting.is_some_and(|setting| setting.secret);
+        let parsed = value.parse().ok();
+
+        if is_secret {
+            value.zeroize();
+        }
+
+        if parsed.is_some() {
+            return parsed;
+        }
+
+        let expected = setting.map_or_else(
+            || std::any::type_name::<T>().to_string(),
+            |setting| setting.kind.describe(),
+        );
+
+        let value = if is_secret {
+            REDACTED.to_string()
+        } else {
+            value
+        };
+
+        self.issues.push(ConfigIssue::Invalid {
+            key,
+            value,
+            expected,
+        });
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        composition::config::{
+            app::{AppConfig, ConfigIssue, LogFormat},
+            runtime::LogLevel,
+        },
+        test_support::fakes::FakeEnv,
+    };
+
+    #[test]
+    fn should_load_config_with_defaults() {
+        let env = FakeEnv::new(&[("SERVER_HOST", "127.0.0.1"), ("SERVER_PORT", "8080")]);
+
+        let config = AppConfig::load(&env).unwrap();
+
+        assert_eq!(config.server.address(), "127.0.0.1:8080");
+        assert_eq!(config.tls, None);
+        assert_eq!(config.database.path, "axum_tdd_api.db");
+        assert_eq!(config.auth.admin_api_token, None);
+        assert_eq!(config.mail.smtp_port, 587);
+        assert_eq!(config.logging.level, LogLevel::Info);
+        assert_eq!(config.logging.format, LogFormat::Pretty);
+        assert_eq!(config.cors.allowed_origins, Vec::<String>::new());
+        assert_eq!(config.rate_limit.sign_up_per_hour, 5);
+    }
+
+    #[test]
+    fn should_report_every_missing_and_invalid_value_together() {
+        let env = FakeEnv::new(&[("SERVER_PORT", "http"), ("LOG_FORMAT", "xml")]);
+
+        let err = AppConfig::load(&env).unwrap_err();
+
+        assert_eq!(
+            err.issues,
+            vec![
+                ConfigIssue::Missing { key: "SERVER_HOST" },
+                ConfigIssue::Invalid {
+                    key: "SERVER_PORT",
+                    value: "http".to_string(),
+                    expected: "a port number between 0 and 65535".to_string(),
+                },
+                ConfigIssue::Invalid {
+                    key: "LOG_FORMAT",
+                    value: "xml".to_string(),
+                    expected: "one of: pretty, json".to_string(),
+                },
+            ]
+        );
+    }
+}
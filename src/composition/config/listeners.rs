@@ -0,0 +1,129 @@
+use std::str::FromStr;
+
+use crate::infrastructure::http::listeners::ListenAddress;
+
+/// Which routes a listener serves. Only internal listeners carry the operator routes, such as
+/// `/metrics` and the admin API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListenerScope {
+    Public,
+    Internal,
+}
+
+impl ListenerScope {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Internal => "internal",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListenerConfig {
+    pub scope: ListenerScope,
+    pub address: ListenAddress,
+}
+
+/// A comma-separated list of `scope=address` entries, such as
+/// `internal=tcp://127.0.0.1:9090,internal=unix:///run/axum_tdd_api.sock`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListenerList(pub Vec<ListenerConfig>);
+
+impl FromStr for ListenerList {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (scope, address) = entry.split_once('=').ok_or(())?;
+
+                let scope = match scope.trim() {
+                    "public" => ListenerScope::Public,
+                    "internal" => ListenerScope::Internal,
+                    _ => return Err(()),
+                };
+
+                let (scheme, target) = address.trim().split_once("://").ok_or(())?;
+
+                if target.is_empty() {
+                    return Err(());
+                }
+
+                let address = match scheme {
+                    "tcp" if target.contains(':') => ListenAddress::Tcp(target.to_string()),
+                    "unix" => ListenAddress::Unix(target.to_string()),
+                    "systemd" => ListenAddress::Systemd(target.to_string()),
+                    _ => return Err(()),
+                };
+
+                Ok(ListenerConfig { scope, address })
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// Permission bits written in octal, as `chmod` takes them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FileMode(pub u32);
+
+impl FromStr for FileMode {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match u32::from_str_radix(value, 8) {
+            Ok(mode) if (3..=4).contains(&value.len()) && mode <= 0o7777 => Ok(Self(mode)),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        composition::config::listeners::{FileMode, ListenerConfig, ListenerList, ListenerScope},
+        infrastructure::http::listeners::ListenAddress,
+    };
+
+    #[test]
+    fn should_parse_listener_entries_and_reject_unknown_ones() {
+        assert_eq!(
+            "public=systemd://http, internal=tcp://127.0.0.1:9090,internal=unix:///run/app.sock"
+                .parse(),
+            Ok(ListenerList(vec![
+                ListenerConfig {
+                    scope: ListenerScope::Public,
+                    address: ListenAddress::Systemd("http".to_string()),
+                },
+                ListenerConfig {
+                    scope: ListenerScope::Internal,
+                    address: ListenAddress::Tcp("127.0.0.1:9090".to_string()),
+                },
+                ListenerConfig {
+                    scope: ListenerScope::Internal,
+                    address: ListenAddress::Unix("/run/app.sock".to_string()),
+                },
+            ]))
+        );
+        assert_eq!("".parse(), Ok(ListenerList::default()));
+
+        for invalid in [
+            "tcp://127.0.0.1:9090",
+            "admin=tcp://127.0.0.1:9090",
+            "public=tcp://localhost",
+            "public=udp://127.0.0.1:53",
+            "internal=unix://",
+        ] {
+            assert_eq!(invalid.parse::<ListenerList>(), Err(()), "{invalid}");
+        }
+
+        assert_eq!("660".parse(), Ok(FileMode(0o660)));
+        assert_eq!("0600".parse(), Ok(FileMode(0o600)));
+        assert_eq!("999".parse::<FileMode>(), Err(()));
+    }
+}
@@ -0,0 +1,232 @@
+use std::{path::PathBuf, time::Duration, time::SystemTime};
+
+use tokio::sync::watch;
+
+use crate::{
+    application::ports::adapters::env::{EnvError, EnvPort},
+    composition::config::{
+        app::{AppConfig, ConfigError},
+        runtime::RuntimeConfig,
+    },
+    infrastructure::adapters::layered_env::{LayeredEnvAdapter, LayeredEnvOptions},
+};
+
+#[derive(Debug)]
+pub enum ReloadError {
+    Sources(EnvError),
+    Invalid(ConfigError),
+}
+
+impl std::fmt::Display for ReloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sources(err) => write!(f, "{err}"),
+            Self::Invalid(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ReloadError {}
+
+/// Re-reads the configuration layers and publishes the [`RuntimeConfig`] part of them to every
+/// subscriber.
+///
+/// A new configuration goes through the same validation as the one loaded at startup and is only
+/// published if it passes, so a typo in a file never reaches the running components.
+///
+/// The sources, secrets included, are read afresh by `sources` on every reload and zeroized once
+/// the configuration is loaded, rather than kept for the life of the process.
+pub struct ConfigReloader {
+    sources: Box<dyn Fn() -> LayeredEnvOptions + Send + Sync>,
+    sender: watch::Sender<RuntimeConfig>,
+}
+
+impl ConfigReloader {
+    #[must_use]
+    pub fn new(
+        sources: impl Fn() -> LayeredEnvOptions + Send + Sync + 'static,
+        initial: RuntimeConfig,
+    ) -> Self {
+        Self {
+            sources: Box::new(sources),
+            sender: watch::Sender::new(initial),
+        }
+    }
+
+    #[must_use]
+    pub fn subscribe(&self) -> watch::Receiver<RuntimeConfig> {
+        self.sender.subscribe()
+    }
+
+    /// Loads the configuration again and publishes it if its runtime part changed. Returns
+    /// whether subscribers were notified.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the published configuration untouched, if:
+    /// - A configuration layer cannot be read (`ReloadError::Sources`)
+    /// - The new configuration is invalid (`ReloadError::Invalid`)
+    pub fn reload(&self) -> Result<bool, ReloadError> {
+        let mut adapter = LayeredEnvAdapter::new((self.sources)());
+
+        adapter.load_env_file().map_err(ReloadError::Sources)?;
+
+        let runtime = AppConfig::load(&adapter)
+            .map_err(ReloadError::Invalid)?
+            .runtime();
+
+        Ok(self.sender.send_if_modified(|current| {
+            if *current == runtime {
+                return false;
+            }
+
+            *current = runtime;
+
+            true
+        }))
+    }
+
+    /// Reloads whenever one of the configuration files changes, checking every `poll_interval`,
+    /// or when the process receives `SIGHUP`. Runs until the task is dropped.
+    pub async fn watch(self, poll_interval: Duration) {
+        let paths = (self.sources)().file_paths();
+        let mut last_modified = modified_times(&paths);
+        let mut interval = tokio::time::interval(poll_interval);
+        let mut hangup = HangupSignal::new();
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    let modified = modified_times(&paths);
+
+                    if modified == last_modified {
+                        continue;
+                    }
+
+                    last_modified = modified;
+                }
+                () = hangup.recv() => {}
+            }
+
+            match self.reload() {
+                Ok(true) => tracing::info!("Configuration reloaded"),
+                Ok(false) => {}
+                Err(err) => {
+                    tracing::error!(
+                        error = %err,
+                        "Could not reload configuration, keeping the previous one"
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn modified_times(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| {
+            std::fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        })
+        .collect()
+}
+
+#[cfg(unix)]
+struct HangupSignal(Option<tokio::signal::unix::Signal>);
+
+#[cfg(unix)]
+impl HangupSignal {
+    fn new() -> Self {
+        Self(tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup()).ok())
+    }
+
+    async fn recv(&mut self) {
+        match &mut self.0 {
+            Some(signal) => {
+                signal.recv().await;
+            }
+            None => std::future::pending().await,
+        }
+    }
+}
+
+/// Platforms without `SIGHUP` only reload when a file changes.
+#[cfg(not(unix))]
+struct HangupSignal;
+
+#[cfg(not(unix))]
+impl HangupSignal {
+    const fn new() -> Self {
+        Self
+    }
+
+    async fn recv(&mut self) {
+        std::future::pending().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        composition::config::{
+            reloader::{ConfigReloader, ReloadError},
+            runtime::{LogLevel, RuntimeConfig},
+        },
+        infrastructure::adapters::layered_env::LayeredEnvOptions,
+    };
+
+    #[test]
+    fn should_publish_valid_changes_and_keep_previous_config_on_invalid_ones() {
+        let dir =
+            std::env::temp_dir().join(format!("axum_tdd_api_reloader_{}", std::process::id()));
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let write_config = |extra: &str| {
+            std::fs::write(
+                dir.join("config.toml"),
+                format!("[server]\nhost = \"127.0.0.1\"\nport = 3000\n\n{extra}"),
+            )
+            .unwrap();
+        };
+
+        write_config("");
+
+        let config_dir = dir.clone();
+        let reloader = ConfigReloader::new(
+            move || LayeredEnvOptions {
+                config_dir: config_dir.clone(),
+                environment: Vec::new(),
+                args: Vec::new(),
+            },
+            RuntimeConfig::default(),
+        );
+        let receiver = reloader.subscribe();
+
+        write_config(
+            "[log]\nlevel = \"debug\"\n\n[cors]\nallowed_origins = [\"https://example.com\"]\n",
+        );
+
+        assert!(reloader.reload().unwrap());
+        assert_eq!(
+            *receiver.borrow(),
+            RuntimeConfig {
+                log_level: LogLevel::Debug,
+                cors_allowed_origins: vec!["https://example.com".to_string()],
+                sign_up_rate_limit_per_hour: 5,
+            }
+        );
+        assert!(!reloader.reload().unwrap());
+
+        write_config("[log]\nlevel = \"loud\"\n");
+
+        assert!(matches!(reloader.reload(), Err(ReloadError::Invalid(_))));
+        assert_eq!(receiver.borrow().log_level, LogLevel::Debug);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
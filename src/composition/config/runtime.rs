@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    #[default]
+    Info,
+    Warn,
+    Error,
+}
+
+impl FromStr for LogLevel {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "trace" => Ok(Self::Trace),
+            "debug" => Ok(Self::Debug),
+            "info" => Ok(Self::Info),
+            "warn" => Ok(Self::Warn),
+            "error" => Ok(Self::Error),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A comma-separated list of origins allowed to call the API from a browser. `*` allows any
+/// origin; every other entry must be a full `http://` or `https://` origin.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AllowedOrigins(pub Vec<String>);
+
+impl FromStr for AllowedOrigins {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let origins = value
+            .split(',')
+            .map(str::trim)
+            .filter(|origin| !origin.is_empty())
+            .map(|origin| origin.trim_end_matches('/').to_string())
+            .collect::<Vec<_>>();
+
+        let is_valid = |origin: &String| {
+            origin == "*"
+                || ["http://", "https://"].iter().any(|scheme| {
+                    origin
+                        .strip_prefix(scheme)
+                        .is_some_and(|host| !host.is_empty() && !host.contains('/'))
+                })
+        };
+
+        if origins.iter().all(is_valid) {
+            Ok(Self(origins))
+        } else {
+            Err(())
+        }
+    }
+}
+
+/// The settings that can change while the server is running.
+///
+/// Components interested in them subscribe to the
+/// [`ConfigReloader`](crate::composition::config::reloader::ConfigReloader) instead of reading
+/// them once at startup.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuntimeConfig {
+    pub log_level: LogLevel,
+    pub cors_allowed_origins: Vec<String>,
+    /// `0` disables the limit.
+    pub sign_up_rate_limit_per_hour: u32,
+}
+
+impl RuntimeConfig {
+    #[must_use]
+    pub fn allows_origin(&self, origin: &str) -> bool {
+        self.cors_allowed_origins
+            .iter()
+            .any(|allowed| allowed == "*" || allowed == origin)
+    }
+}
@@ -0,0 +1,432 @@
+use crate::infrastructure::adapters::id_generator::snowflake::MAX_WORKER_ID;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingKind {
+    String,
+    Port,
+    Integer,
+    BoundedInteger { max: u16 },
+    OriginList,
+    ListenerList,
+    FileMode,
+    Timestamp,
+    Enum(&'static [&'static str]),
+}
+
+/// Declares one configuration key: what it holds, whether it must be provided and what it falls
+/// back to otherwise.
+///
+/// Secret values are never echoed back in configuration errors. Reloadable values are picked up
+/// from the configuration files while the server runs; the others need a restart.
+#[derive(Debug)]
+pub struct Setting {
+    pub key: &'static str,
+    pub section: &'static str,
+    pub kind: SettingKind,
+    pub required: bool,
+    pub secret: bool,
+    pub reloadable: bool,
+    pub default: Option<&'static str>,
+    pub description: &'static str,
+}
+
+pub const SETTINGS: &[Setting] = &[
+    Setting {
+        key: "SERVER_HOST",
+        section: "server",
+        kind: SettingKind::String,
+        required: true,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "Interface the HTTP server binds to",
+    },
+    Setting {
+        key: "SERVER_PORT",
+        section: "server",
+        kind: SettingKind::Port,
+        required: true,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "Port the HTTP server listens on",
+    },
+    Setting {
+        key: "SERVER_SHUTDOWN_TIMEOUT_SECONDS",
+        section: "server",
+        kind: SettingKind::Integer,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("30"),
+        description: "Seconds in-flight requests get to finish once shutdown begins",
+    },
+    Setting {
+        key: "SERVER_LISTENERS",
+        section: "server",
+        kind: SettingKind::ListenerList,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some(""),
+        description: "Additional listeners; a public one replaces SERVER_HOST:SERVER_PORT and an \
+                      internal one serves the metrics and admin routes, which public ones never do",
+    },
+    Setting {
+        key: "SERVER_UNIX_SOCKET_MODE",
+        section: "server",
+        kind: SettingKind::FileMode,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("660"),
+        description: "Permissions of the Unix domain sockets the server creates",
+    },
+    Setting {
+        key: "TLS_CERT_PATH",
+        section: "tls",
+        kind: SettingKind::String,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "PEM certificate chain served over HTTPS, which stays disabled when unset",
+    },
+    Setting {
+        key: "TLS_KEY_PATH",
+        section: "tls",
+        kind: SettingKind::String,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "PEM private key of the certificate, required along with TLS_CERT_PATH",
+    },
+    Setting {
+        key: "TLS_CLIENT_CA_PATH",
+        section: "tls",
+        kind: SettingKind::String,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "PEM certificates trusted to sign client certificates, enabling mutual TLS",
+    },
+    Setting {
+        key: "TLS_CLIENT_AUTH",
+        section: "tls",
+        kind: SettingKind::Enum(&["required", "optional"]),
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("required"),
+        description: "Whether clients must present a certificate once mutual TLS is enabled",
+    },
+    Setting {
+        key: "TLS_REDIRECT_PORT",
+        section: "tls",
+        kind: SettingKind::Port,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "Port of a plain HTTP listener, on the host of the public TLS listener, redirecting every request to it",
+    },
+    Setting {
+        key: "DATABASE_PATH",
+        section: "database",
+        kind: SettingKind::String,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("axum_tdd_api.db"),
+        description: "Path of the SQLite database file",
+    },
+    Setting {
+        key: "ADMIN_API_TOKEN",
+        section: "auth",
+        kind: SettingKind::String,
+        required: false,
+        secret: true,
+        reloadable: false,
+        default: None,
+        description: "Bearer token for the admin routes, which stay unmounted when unset",
+    },
+    Setting {
+        key: "MAIL_SMTP_HOST",
+        section: "mail",
+        kind: SettingKind::String,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "SMTP relay used to send e-mails",
+    },
+    Setting {
+        key: "MAIL_SMTP_PORT",
+        section: "mail",
+        kind: SettingKind::Port,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("587"),
+        description: "Port of the SMTP relay",
+    },
+    Setting {
+        key: "MAIL_SMTP_PASSWORD",
+        section: "mail",
+        kind: SettingKind::String,
+        required: false,
+        secret: true,
+        reloadable: false,
+        default: None,
+        description: "Password used to authenticate against the SMTP relay",
+    },
+    Setting {
+        key: "MAIL_FROM",
+        section: "mail",
+        kind: SettingKind::String,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "Sender address of outgoing e-mails",
+    },
+    Setting {
+        key: "LOG_LEVEL",
+        section: "logging",
+        kind: SettingKind::Enum(&["trace", "debug", "info", "warn", "error"]),
+        required: false,
+        secret: false,
+        reloadable: true,
+        default: Some("info"),
+        description: "Minimum level of the log lines written",
+    },
+    Setting {
+        key: "LOG_FORMAT",
+        section: "logging",
+        kind: SettingKind::Enum(&["pretty", "json"]),
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("pretty"),
+        description: "Whether log lines are human-readable text or JSON",
+    },
+    Setting {
+        key: "AUDIT_LOG_PATH",
+        section: "logging",
+        kind: SettingKind::String,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("audit.log.jsonl"),
+        description: "Path of the append-only audit log file, written on every sign-up",
+    },
+    Setting {
+        key: "AUDIT_LOG_STORE",
+        section: "logging",
+        kind: SettingKind::Enum(&["file", "sqlite"]),
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("file"),
+        description: "Where the audit log is kept: in AUDIT_LOG_PATH, or in the database",
+    },
+    Setting {
+        key: "CORS_ALLOWED_ORIGINS",
+        section: "cors",
+        kind: SettingKind::OriginList,
+        required: false,
+        secret: false,
+        reloadable: true,
+        default: Some(""),
+        description: "Origins allowed to call the API from a browser",
+    },
+    Setting {
+        key: "RATE_LIMIT_SIGN_UP_PER_HOUR",
+        section: "rate_limit",
+        kind: SettingKind::Integer,
+        required: false,
+        secret: false,
+        reloadable: true,
+        default: Some("5"),
+        description: "Sign-ups allowed per client and hour, 0 disabling the limit",
+    },
+    Setting {
+        key: "RATE_LIMIT_STORE",
+        section: "rate_limit",
+        kind: SettingKind::Enum(&["memory", "sqlite"]),
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("memory"),
+        description: "Where rate limits are counted: per instance, or in the shared database",
+    },
+    Setting {
+        key: "IDS_GENERATOR",
+        section: "ids",
+        kind: SettingKind::Enum(&["uuid_v4", "uuid_v7", "ulid", "snowflake"]),
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("uuid_v7"),
+        description: "How user ids are generated; every scheme but uuid_v4 sorts by creation time",
+    },
+    Setting {
+        key: "IDS_SNOWFLAKE_WORKER_ID",
+        section: "ids",
+        kind: SettingKind::BoundedInteger { max: MAX_WORKER_ID },
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("0"),
+        description: "Worker id embedded in Snowflake ids, unique to each running instance",
+    },
+    Setting {
+        key: "API_V1_DEPRECATED_AT",
+        section: "api",
+        kind: SettingKind::Timestamp,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "When version 1 of the API is deprecated, announced in the Deprecation header of its responses",
+    },
+    Setting {
+        key: "API_V1_SUNSET_AT",
+        section: "api",
+        kind: SettingKind::Timestamp,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "When version 1 of the API stops being served, announced in the Sunset header of its responses",
+    },
+    Setting {
+        key: "API_V2_DEPRECATED_AT",
+        section: "api",
+        kind: SettingKind::Timestamp,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "When version 2 of the API is deprecated, announced in the Deprecation header of its responses",
+    },
+    Setting {
+        key: "API_V2_SUNSET_AT",
+        section: "api",
+        kind: SettingKind::Timestamp,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "When version 2 of the API stops being served, announced in the Sunset header of its responses",
+    },
+    Setting {
+        key: "API_UNVERSIONED_DEPRECATED_AT",
+        section: "api",
+        kind: SettingKind::Timestamp,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "When the paths from before versioning, such as /auth/sign-up, were deprecated in favour of /v1; they are only served once this is set",
+    },
+    Setting {
+        key: "API_UNVERSIONED_SUNSET_AT",
+        section: "api",
+        kind: SettingKind::Timestamp,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "When the paths from before versioning stop being served; requires API_UNVERSIONED_DEPRECATED_AT",
+    },
+    Setting {
+        key: "HEALTH_CHECK_TIMEOUT_MS",
+        section: "health",
+        kind: SettingKind::Integer,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("2000"),
+        description: "Milliseconds each readiness check gets before it is reported as down",
+    },
+    Setting {
+        key: "HEALTH_CACHE_TTL_MS",
+        section: "health",
+        kind: SettingKind::Integer,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("1000"),
+        description: "Milliseconds the readiness and startup probes answer from the last run of the checks",
+    },
+    Setting {
+        key: "HEALTH_MIN_FREE_DISK_MB",
+        section: "health",
+        kind: SettingKind::Integer,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("100"),
+        description: "Free disk space below which file-backed adapters are reported as down",
+    },
+    Setting {
+        key: "OTLP_ENDPOINT",
+        section: "otlp",
+        kind: SettingKind::String,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: None,
+        description: "Collector spans are exported to over OTLP, which stays disabled when unset",
+    },
+    Setting {
+        key: "OTLP_PROTOCOL",
+        section: "otlp",
+        kind: SettingKind::Enum(&["grpc", "http"]),
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("grpc"),
+        description: "Whether spans are exported over gRPC or HTTP/protobuf",
+    },
+    Setting {
+        key: "OTLP_SERVICE_NAME",
+        section: "otlp",
+        kind: SettingKind::String,
+        required: false,
+        secret: false,
+        reloadable: false,
+        default: Some("axum_tdd_api"),
+        description: "Service name exported spans are attributed to",
+    },
+];
+
+#[must_use]
+pub fn find_setting(key: &str) -> Option<&'static Setting> {
+    SETTINGS.iter().find(|setting| setting.key == key)
+}
+
+impl SettingKind {
+    #[must_use]
+    pub fn describe(self) -> String {
+        match self {
+            Self::String => "a string".to_string(),
+            Self::Port => "a port number between 0 and 65535".to_string(),
+            Self::Integer => "a whole number between 0 and 4294967295".to_string(),
+            Self::BoundedInteger { max } => format!("a whole number between 0 and {max}"),
+            Self::OriginList => "a comma-separated list of http(s) origins, or *".to_string(),
+            Self::ListenerList => "a comma-separated list of public|internal=tcp://host:port, \
+                                   unix:///path or systemd://name entries"
+                .to_string(),
+            Self::FileMode => "an octal file mode such as 660".to_string(),
+            Self::Timestamp => {
+                "a date such as 2024-06-30, or a UTC date and time such as 2024-06-30T12:00:00Z"
+                    .to_string()
+            }
+            Self::Enum(values) => format!("one of: {}", values.join(", ")),
+        }
+    }
+}
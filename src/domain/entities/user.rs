@@ -1,29 +1,218 @@
-#[derive(Debug, PartialEq, Eq)]
-pub struct UserEntity {
+use crate::domain::values::{secret::REDACTED, timestamp::Timestamp};
+
+/// What a user signs up with, once their password is hashed.
+pub struct NewUser {
+    pub id: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    pub password_hash: String,
+}
+
+/// Every field of a user as a repository stored it, to restore the entity from.
+pub struct StoredUser {
     pub id: String,
     pub first_name: String,
     pub last_name: String,
     pub email: String,
-    pub created_at: i64,
-    pub updated_at: i64,
+    pub password_hash: String,
+    pub email_verified_at: Option<Timestamp>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+/// A registered user.
+///
+/// Fields are read through accessors and only change through the methods below, which move
+/// `updated_at` to the time of the change. Callers read that time from their clock, so the entity
+/// stays free of it. The password hash is never serialized nor printed.
+#[derive(Clone, PartialEq, Eq)]
+pub struct UserEntity {
+    id: String,
+    first_name: String,
+    last_name: String,
+    email: String,
+    password_hash: String,
+    email_verified_at: Option<Timestamp>,
+    created_at: Timestamp,
+    updated_at: Timestamp,
 }
 
 impl UserEntity {
-    pub const fn new(
-        id: String,
-        first_name: String,
-        last_name: String,
-        email: String,
-        created_at: i64,
-        updated_at: i64,
-    ) -> Self {
+    /// A user registered at `now`, whose e-mail address is still to be verified.
+    #[must_use]
+    pub fn register(new_user: NewUser, now: Timestamp) -> Self {
+        Self {
+            id: new_user.id,
+            first_name: new_user.first_name,
+            last_name: new_user.last_name,
+            email: new_user.email,
+            password_hash: new_user.password_hash,
+            email_verified_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[must_use]
+    pub fn restore(stored: StoredUser) -> Self {
         Self {
-            id,
-            first_name,
-            last_name,
-            email,
-            created_at,
-            updated_at,
+            id: stored.id,
+            first_name: stored.first_name,
+            last_name: stored.last_name,
+            email: stored.email,
+            password_hash: stored.password_hash,
+            email_verified_at: stored.email_verified_at,
+            created_at: stored.created_at,
+            updated_at: stored.updated_at,
         }
     }
+
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn first_name(&self) -> &str {
+        &self.first_name
+    }
+
+    #[must_use]
+    pub fn last_name(&self) -> &str {
+        &self.last_name
+    }
+
+    #[must_use]
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    /// The hash to check sign-in attempts against, and for repositories to store.
+    #[must_use]
+    pub fn password_hash(&self) -> &str {
+        &self.password_hash
+    }
+
+    #[must_use]
+    pub const fn email_verified_at(&self) -> Option<Timestamp> {
+        self.email_verified_at
+    }
+
+    #[must_use]
+    pub const fn is_email_verified(&self) -> bool {
+        self.email_verified_at.is_some()
+    }
+
+    #[must_use]
+    pub const fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[must_use]
+    pub const fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    pub fn change_password(&mut self, password_hash: String, now: Timestamp) {
+        self.password_hash = password_hash;
+        self.touch(now);
+    }
+
+    pub fn rename(&mut self, first_name: String, last_name: String, now: Timestamp) {
+        self.first_name = first_name;
+        self.last_name = last_name;
+        self.touch(now);
+    }
+
+    /// Marks the e-mail address as verified. Verifying it again changes nothing, so the first
+    /// verification time is kept.
+    pub fn verify_email(&mut self, now: Timestamp) {
+        if self.email_verified_at.is_none() {
+            self.touch(now);
+            self.email_verified_at = Some(self.updated_at);
+        }
+    }
+
+    /// The wall clock can be set back, but `updated_at` never goes before the previous change.
+    fn touch(&mut self, now: Timestamp) {
+        self.updated_at = self.updated_at.max(now);
+    }
+}
+
+impl std::fmt::Debug for UserEntity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UserEntity")
+            .field("id", &self.id)
+            .field("first_name", &self.first_name)
+            .field("last_name", &self.last_name)
+            .field("email", &self.email)
+            .field("password_hash", &REDACTED)
+            .field("email_verified_at", &self.email_verified_at)
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::domain::{
+        entities::user::{NewUser, UserEntity},
+        values::timestamp::Timestamp,
+    };
+
+    const REGISTERED_AT: Timestamp = Timestamp::from_unix_seconds(1_700_000_000);
+
+    fn register() -> UserEntity {
+        UserEntity::register(
+            NewUser {
+                id: "user_1".to_string(),
+                first_name: "John".to_string(),
+                last_name: "Doe".to_string(),
+                email: "john.doe@mail.com".to_string(),
+                password_hash: "$argon2id$v=19$hash".to_string(),
+            },
+            REGISTERED_AT,
+        )
+    }
+
+    #[test]
+    fn should_move_updated_at_with_every_change() {
+        let mut user = register();
+
+        assert_eq!(user.created_at(), REGISTERED_AT);
+        assert_eq!(user.updated_at(), REGISTERED_AT);
+        assert!(!user.is_email_verified());
+
+        let renamed_at = REGISTERED_AT.saturating_add(Duration::from_mins(1));
+        user.rename("Jane".to_string(), "Roe".to_string(), renamed_at);
+
+        assert_eq!((user.first_name(), user.last_name()), ("Jane", "Roe"));
+        assert_eq!(user.updated_at(), renamed_at);
+
+        let verified_at = renamed_at.saturating_add(Duration::from_mins(1));
+        user.verify_email(verified_at);
+        user.verify_email(verified_at.saturating_add(Duration::from_mins(1)));
+
+        assert_eq!(user.email_verified_at(), Some(verified_at));
+        assert_eq!(user.updated_at(), verified_at);
+
+        // A clock set back must not make the last change look older than the previous one.
+        user.change_password("new_hash".to_string(), REGISTERED_AT);
+
+        assert_eq!(user.password_hash(), "new_hash");
+        assert_eq!(user.updated_at(), verified_at);
+        assert_eq!(user.created_at(), REGISTERED_AT);
+    }
+
+    #[test]
+    fn should_not_print_the_password_hash() {
+        let printed = format!("{:?}", register());
+
+        assert!(!printed.contains("argon2id"));
+        assert!(printed.contains("[REDACTED]"));
+    }
 }
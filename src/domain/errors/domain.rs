@@ -1,5 +1,6 @@
 #[derive(Debug, PartialEq, Eq)]
 pub enum DomainError {
+    Conflict(String),
     Internal(String),
     PasswordMismatch,
     UserAlreadyExists,
@@ -8,6 +9,7 @@ pub enum DomainError {
 impl std::fmt::Display for DomainError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Conflict(field) => write!(f, "Conflicting value for unique field '{field}'"),
             Self::Internal(err) => write!(f, "Something went wrong: {err}"),
             Self::PasswordMismatch => write!(f, "The provided passwords do not match"),
             Self::UserAlreadyExists => {
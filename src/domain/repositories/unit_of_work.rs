@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::domain::{errors::domain::DomainError, repositories::user::UserPersistencePort};
+
+pub trait UnitOfWorkPort: Send + Sync {
+    /// Opens a new transaction spanning every repository it hands out.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DomainError::Internal`] if the underlying store cannot start a transaction.
+    fn begin(&self) -> Result<Box<dyn TransactionPort>, DomainError>;
+}
+
+pub trait TransactionPort: Send + Sync {
+    /// Returns the user repository bound to this transaction.
+    fn users(&self) -> Arc<dyn UserPersistencePort>;
+
+    /// Makes every write performed through this transaction visible at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DomainError::Internal`] if the changes cannot be persisted.
+    fn commit(&self) -> Result<(), DomainError>;
+
+    /// Discards every write performed through this transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DomainError::Internal`] if the changes cannot be discarded.
+    fn rollback(&self) -> Result<(), DomainError>;
+}
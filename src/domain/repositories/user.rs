@@ -1,11 +1,21 @@
 use crate::domain::{
-    dtos::user::{CreateUserDto, FindUserByEmailDto},
-    entities::user::UserEntity,
-    errors::domain::DomainError,
+    dtos::user::FindUserByEmailDto, entities::user::UserEntity, errors::domain::DomainError,
 };
 
 #[async_trait::async_trait]
 pub trait UserPersistencePort: Send + Sync {
-    fn create(&self, dto: CreateUserDto) -> Result<UserEntity, DomainError>;
+    /// Persists a newly registered user, password hash included, and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DomainError::Conflict`] if the e-mail is already taken, or a
+    /// [`DomainError::Internal`] if the user cannot be stored.
+    fn create(&self, user: UserEntity) -> Result<UserEntity, DomainError>;
+
+    /// Looks up a user by e-mail address, with the password hash it was stored with.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DomainError::Internal`] if the lookup cannot be performed.
     fn find_by_email(&self, dto: FindUserByEmailDto) -> Result<Option<UserEntity>, DomainError>;
 }
@@ -0,0 +1,158 @@
+use std::{fmt, time::Duration};
+
+use serde::{Serialize, Serializer};
+
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// A point in UTC time, to the millisecond, counted from the Unix epoch.
+///
+/// It is shown and serialized in RFC 3339, always in UTC and with milliseconds, such as
+/// `2023-11-14T22:13:20.000Z`, so timestamps in API output also sort as strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+    pub const UNIX_EPOCH: Self = Self(0);
+
+    #[must_use]
+    pub const fn from_unix_millis(millis: i64) -> Self {
+        Self(millis)
+    }
+
+    #[must_use]
+    pub const fn from_unix_seconds(seconds: i64) -> Self {
+        Self(seconds.saturating_mul(1000))
+    }
+
+    /// Midnight UTC of a proleptic Gregorian date, which the caller has checked to exist.
+    #[must_use]
+    pub const fn from_date(year: i64, month: i64, day: i64) -> Self {
+        Self(days_from_civil(year, month, day) * MILLIS_PER_DAY)
+    }
+
+    #[must_use]
+    pub const fn unix_millis(self) -> i64 {
+        self.0
+    }
+
+    /// Whole seconds since the Unix epoch, rounded down.
+    #[must_use]
+    pub const fn unix_seconds(self) -> i64 {
+        self.0.div_euclid(1000)
+    }
+
+    #[must_use]
+    pub fn saturating_add(self, duration: Duration) -> Self {
+        Self(
+            self.0
+                .saturating_add(i64::try_from(duration.as_millis()).unwrap_or(i64::MAX)),
+        )
+    }
+
+    /// The time elapsed from `earlier` to this timestamp, or `None` if `earlier` is later.
+    #[must_use]
+    pub fn duration_since(self, earlier: Self) -> Option<Duration> {
+        u64::try_from(self.0.checked_sub(earlier.0)?)
+            .ok()
+            .map(Duration::from_millis)
+    }
+
+    /// The UTC date, as year, month and day.
+    #[must_use]
+    pub const fn date(self) -> (i64, i64, i64) {
+        civil_from_days(self.0.div_euclid(MILLIS_PER_DAY))
+    }
+
+    #[must_use]
+    pub fn to_rfc3339(self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day) = self.date();
+        let millis_of_day = self.0.rem_euclid(MILLIS_PER_DAY);
+
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+            millis_of_day / 3_600_000,
+            millis_of_day / 60_000 % 60,
+            millis_of_day / 1000 % 60,
+            millis_of_day % 1000
+        )
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Converts a proleptic Gregorian date to days since the Unix epoch, the inverse of
+/// [`civil_from_days`].
+const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Converts days since the Unix epoch to a proleptic Gregorian date, following Howard Hinnant's
+/// `civil_from_days` algorithm.
+const fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let shifted = days + 719_468;
+    let era = shifted.div_euclid(146_097);
+    let day_of_era = shifted.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // Months are counted from March, so the leap day falls at the end of the year.
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::values::timestamp::Timestamp;
+
+    #[test]
+    fn should_format_as_rfc3339_in_utc_with_milliseconds() {
+        for (millis, expected) in [
+            (0, "1970-01-01T00:00:00.000Z"),
+            (-1, "1969-12-31T23:59:59.999Z"),
+            (1_700_000_000_000, "2023-11-14T22:13:20.000Z"),
+            (951_782_400_123, "2000-02-29T00:00:00.123Z"),
+            (253_402_300_799_999, "9999-12-31T23:59:59.999Z"),
+        ] {
+            assert_eq!(Timestamp::from_unix_millis(millis).to_rfc3339(), expected);
+        }
+
+        assert_eq!(
+            serde_json::to_value(Timestamp::from_unix_seconds(1_700_000_000)).unwrap(),
+            "2023-11-14T22:13:20.000Z"
+        );
+        assert_eq!(
+            Timestamp::from_date(2000, 2, 29),
+            Timestamp::from_unix_millis(951_782_400_000)
+        );
+        assert_eq!(
+            Timestamp::from_unix_millis(951_782_400_123).date(),
+            (2000, 2, 29)
+        );
+    }
+}
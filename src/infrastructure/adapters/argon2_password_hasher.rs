@@ -0,0 +1,41 @@
+use argon2::{
+    Argon2,
+    password_hash::{PasswordHasher, PasswordVerifier},
+};
+use zeroize::Zeroizing;
+
+use crate::application::ports::adapters::password_hasher::{PasswordHashError, PasswordHasherPort};
+
+/// Hashes passwords with Argon2id and its default parameters, under a random salt, into a PHC
+/// string that carries everything needed to verify it later.
+pub struct Argon2PasswordHasherAdapter;
+
+impl PasswordHasherPort for Argon2PasswordHasherAdapter {
+    fn hash_password(&self, password: String) -> Result<String, PasswordHashError> {
+        let password = Zeroizing::new(password);
+
+        // Hashing only fails on parameters out of range or an unavailable system RNG.
+        Argon2::default()
+            .hash_password(password.as_bytes())
+            .map(|password_hash| password_hash.to_string())
+            .map_err(|err| PasswordHashError(err.to_string()))
+    }
+
+    fn verify_password(&self, password: &str, password_hash: &str) -> bool {
+        // The parameters and salt are read from the hash, so hashes made with older parameters
+        // keep verifying.
+        Argon2::default()
+            .verify_password(password.as_bytes(), password_hash)
+            .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        infrastructure::adapters::argon2_password_hasher::Argon2PasswordHasherAdapter,
+        test_support::contracts::password_hasher::password_hasher_contract,
+    };
+
+    password_hasher_contract!(|| Argon2PasswordHasherAdapter);
+}
@@ -0,0 +1,69 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::{
+    application::ports::adapters::{
+        audit_log::{AuditEntry, AuditEvent, AuditLogError, AuditLogPort, AuditQuery},
+        time::TimePort,
+    },
+    infrastructure::adapters::audit_log::chain,
+};
+
+/// Keeps the chain in this process only, for tests and throwaway environments.
+pub struct InMemoryAuditLogAdapter {
+    time: Arc<dyn TimePort>,
+    entries: Mutex<Vec<AuditEntry>>,
+}
+
+impl InMemoryAuditLogAdapter {
+    #[must_use]
+    pub fn new(time: Arc<dyn TimePort>) -> Self {
+        Self {
+            time,
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Every entry recorded so far, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AuditLogError::Storage`] if a previous write panicked while holding the log.
+    pub fn entries(&self) -> Result<Vec<AuditEntry>, AuditLogError> {
+        Ok(self.lock_entries()?.clone())
+    }
+
+    fn lock_entries(&self) -> Result<MutexGuard<'_, Vec<AuditEntry>>, AuditLogError> {
+        self.entries
+            .lock()
+            .map_err(|_| AuditLogError::Storage("Audit log entries are poisoned".to_string()))
+    }
+}
+
+impl AuditLogPort for InMemoryAuditLogAdapter {
+    fn record(&self, event: AuditEvent) -> Result<AuditEntry, AuditLogError> {
+        let mut entries = self.lock_entries()?;
+
+        let previous_hash = entries
+            .last()
+            .map_or_else(|| chain::GENESIS_HASH.to_string(), |last| last.hash.clone());
+        let entry = chain::seal(
+            event,
+            entries.len() as u64 + 1,
+            self.time.now().unix_seconds(),
+            previous_hash,
+        );
+
+        entries.push(entry.clone());
+        drop(entries);
+
+        Ok(entry)
+    }
+
+    fn query(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, AuditLogError> {
+        Ok(chain::select(self.entries()?, query))
+    }
+
+    fn verify_chain(&self) -> Result<(), AuditLogError> {
+        chain::verify(&self.lock_entries()?)
+    }
+}
@@ -0,0 +1,297 @@
+use std::{
+    fs::OpenOptions,
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application::ports::adapters::{
+        audit_log::{AuditEntry, AuditEvent, AuditLogError, AuditLogPort, AuditQuery},
+        time::TimePort,
+    },
+    infrastructure::adapters::audit_log::chain::{self, GENESIS_HASH},
+};
+
+#[derive(Serialize, Deserialize)]
+struct AuditLine {
+    sequence: u64,
+    action: String,
+    actor: Option<String>,
+    target: Option<String>,
+    ip_address: Option<String>,
+    user_agent: Option<String>,
+    occurred_at: i64,
+    previous_hash: String,
+    hash: String,
+}
+
+impl From<&AuditEntry> for AuditLine {
+    fn from(entry: &AuditEntry) -> Self {
+        Self {
+            sequence: entry.sequence,
+            action: entry.action.as_str().to_string(),
+            actor: entry.actor.clone(),
+            target: entry.target.clone(),
+            ip_address: entry.ip_address.clone(),
+            user_agent: entry.user_agent.clone(),
+            occurred_at: entry.occurred_at,
+            previous_hash: entry.previous_hash.clone(),
+            hash: entry.hash.clone(),
+        }
+    }
+}
+
+impl TryFrom<AuditLine> for AuditEntry {
+    type Error = AuditLogError;
+
+    fn try_from(line: AuditLine) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sequence: line.sequence,
+            action: line.action.parse()?,
+            actor: line.actor,
+            target: line.target,
+            ip_address: line.ip_address,
+            user_agent: line.user_agent,
+            occurred_at: line.occurred_at,
+            previous_hash: line.previous_hash,
+            hash: line.hash,
+        })
+    }
+}
+
+struct ChainHead {
+    sequence: u64,
+    hash: String,
+}
+
+/// Appends one JSON document per line to a file that is never rewritten.
+pub struct JsonLinesAuditLogAdapter {
+    path: PathBuf,
+    time: Arc<dyn TimePort>,
+    head: Mutex<ChainHead>,
+}
+
+impl JsonLinesAuditLogAdapter {
+    /// Opens the log at `path`, creating it on the first write if it does not exist yet.
+    ///
+    /// The existing chain is verified first, so new entries are never sealed onto a log that was
+    /// edited or cut in the middle. A last line missing its newline was torn by a crash while
+    /// being appended, before its entry was ever acknowledged, so it is cut off with a warning.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AuditLogError::Storage`] if an existing log cannot be read or repaired, and an
+    /// [`AuditLogError::Tampered`] if its chain is broken.
+    pub fn open(path: impl Into<PathBuf>, time: Arc<dyn TimePort>) -> Result<Self, AuditLogError> {
+        let path = path.into();
+
+        discard_torn_tail(&path)?;
+
+        let entries = read_entries(&path)?;
+
+        chain::verify(&entries)?;
+
+        let head = entries.last().map_or_else(
+            || ChainHead {
+                sequence: 0,
+                hash: GENESIS_HASH.to_string(),
+            },
+            |entry| ChainHead {
+                sequence: entry.sequence,
+                hash: entry.hash.clone(),
+            },
+        );
+
+        Ok(Self {
+            path,
+            time,
+            head: Mutex::new(head),
+        })
+    }
+}
+
+impl AuditLogPort for JsonLinesAuditLogAdapter {
+    fn record(&self, event: AuditEvent) -> Result<AuditEntry, AuditLogError> {
+        let mut head = self
+            .head
+            .lock()
+            .map_err(|_| AuditLogError::Storage("Audit log head is poisoned".to_string()))?;
+
+        let entry = chain::seal(
+            event,
+            head.sequence + 1,
+            self.time.now().unix_seconds(),
+            head.hash.clone(),
+        );
+
+        let mut line = serde_json::to_string(&AuditLine::from(&entry))
+            .map_err(|err| AuditLogError::Storage(err.to_string()))?;
+
+        line.push('\n');
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|err| AuditLogError::Storage(err.to_string()))?;
+
+        file.write_all(line.as_bytes())
+            .and_then(|()| file.sync_data())
+            .map_err(|err| AuditLogError::Storage(err.to_string()))?;
+
+        head.sequence = entry.sequence;
+        head.hash.clone_from(&entry.hash);
+        drop(head);
+
+        Ok(entry)
+    }
+
+    fn query(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, AuditLogError> {
+        Ok(chain::select(read_entries(&self.path)?, query))
+    }
+
+    fn verify_chain(&self) -> Result<(), AuditLogError> {
+        chain::verify(&read_entries(&self.path)?)
+    }
+}
+
+/// Truncates the log after its last complete line.
+fn discard_torn_tail(path: &Path) -> Result<(), AuditLogError> {
+    let contents = match std::fs::read(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(AuditLogError::Storage(err.to_string())),
+    };
+
+    if contents.last().is_none_or(|byte| *byte == b'\n') {
+        return Ok(());
+    }
+
+    let complete_len = contents
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map_or(0, |newline| newline + 1);
+
+    OpenOptions::new()
+        .write(true)
+        .open(path)
+        .and_then(|file| {
+            file.set_len(complete_len as u64)?;
+            file.sync_data()
+        })
+        .map_err(|err| AuditLogError::Storage(err.to_string()))?;
+
+    tracing::warn!(
+        path = %path.display(),
+        discarded_bytes = contents.len() - complete_len,
+        "Discarded the partially written last line of the audit log"
+    );
+
+    Ok(())
+}
+
+fn read_entries(path: &PathBuf) -> Result<Vec<AuditEntry>, AuditLogError> {
+    let file = match std::fs::File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(AuditLogError::Storage(err.to_string())),
+    };
+
+    BufReader::new(file)
+        .lines()
+        .filter(|line| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+        .map(|line| {
+            let line = line.map_err(|err| AuditLogError::Storage(err.to_string()))?;
+
+            serde_json::from_str::<AuditLine>(&line)
+                .map_err(|err| AuditLogError::Storage(err.to_string()))?
+                .try_into()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, sync::Arc};
+
+    use crate::{
+        application::ports::adapters::{
+            audit_log::{AuditAction, AuditEvent, AuditLogError, AuditLogPort},
+            time::TimePort,
+        },
+        domain::values::timestamp::Timestamp,
+        infrastructure::adapters::audit_log::json_lines::JsonLinesAuditLogAdapter,
+        test_support::{
+            contracts::audit_log::audit_log_contract, fakes::ManualClock, temp_file::TempFile,
+        },
+    };
+
+    fn sign_up(actor: &str) -> AuditEvent {
+        AuditEvent {
+            action: AuditAction::SignUp,
+            actor: Some(actor.to_string()),
+            target: Some(actor.to_string()),
+            ip_address: None,
+            user_agent: None,
+        }
+    }
+
+    audit_log_contract!(
+        |path| JsonLinesAuditLogAdapter::open(
+            path,
+            Arc::new(ManualClock::new(Timestamp::from_unix_seconds(1_000_000))),
+        ),
+        |path| {
+            let contents = std::fs::read_to_string(path).unwrap();
+
+            std::fs::write(path, contents.replacen("user_2", "user_3", 1)).unwrap();
+        }
+    );
+
+    #[test]
+    fn should_cut_off_a_torn_last_line_but_refuse_a_broken_chain() {
+        let file = TempFile::new("audit_log_torn_tail");
+        let time: Arc<dyn TimePort> = Arc::new(ManualClock::new(Timestamp::from_unix_seconds(0)));
+        let audit_log = JsonLinesAuditLogAdapter::open(file.path(), time.clone()).unwrap();
+
+        let first = audit_log.record(sign_up("user_1")).unwrap();
+        let second = audit_log.record(sign_up("user_2")).unwrap();
+
+        drop(audit_log);
+
+        let complete = std::fs::read_to_string(file.path()).unwrap();
+
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(file.path())
+            .unwrap()
+            .write_all(br#"{"sequence":3,"action":"sign_"#)
+            .unwrap();
+
+        let audit_log = JsonLinesAuditLogAdapter::open(file.path(), time.clone()).unwrap();
+
+        assert_eq!(std::fs::read_to_string(file.path()).unwrap(), complete);
+
+        let third = audit_log.record(sign_up("user_3")).unwrap();
+
+        assert_eq!(third.sequence, 3);
+        assert_eq!(third.previous_hash, second.hash);
+        assert_eq!(audit_log.verify_chain(), Ok(()));
+
+        drop(audit_log);
+
+        std::fs::write(
+            file.path(),
+            complete.replacen(&first.hash, &"0".repeat(first.hash.len()), 1),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            JsonLinesAuditLogAdapter::open(file.path(), time),
+            Err(AuditLogError::Tampered { .. })
+        ));
+    }
+}
@@ -0,0 +1,242 @@
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use rusqlite::{Connection, OptionalExtension, params};
+
+use crate::{
+    application::ports::adapters::{
+        audit_log::{AuditEntry, AuditEvent, AuditLogError, AuditLogPort, AuditQuery},
+        time::TimePort,
+    },
+    infrastructure::adapters::audit_log::chain::{self, GENESIS_HASH},
+};
+
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+const MIGRATIONS: &str = "
+    CREATE TABLE IF NOT EXISTS audit_log (
+        sequence INTEGER PRIMARY KEY NOT NULL,
+        action TEXT NOT NULL,
+        actor TEXT,
+        target TEXT,
+        ip_address TEXT,
+        user_agent TEXT,
+        occurred_at INTEGER NOT NULL,
+        previous_hash TEXT NOT NULL,
+        hash TEXT NOT NULL
+    );
+";
+
+/// Stores the audit log in an `audit_log` table, next to the rest of the application data.
+pub struct SqliteAuditLogAdapter {
+    connection: Mutex<Connection>,
+    time: Arc<dyn TimePort>,
+}
+
+impl SqliteAuditLogAdapter {
+    /// Opens the database at `path`, makes sure the `audit_log` table exists and verifies the
+    /// chain it holds, so new entries are never sealed onto an edited log.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AuditLogError::Storage`] if the database cannot be opened or migrated, and an
+    /// [`AuditLogError::Tampered`] if its chain is broken.
+    pub fn open(path: impl AsRef<Path>, time: Arc<dyn TimePort>) -> Result<Self, AuditLogError> {
+        let connection =
+            Connection::open(path).map_err(|err| AuditLogError::Storage(err.to_string()))?;
+
+        connection
+            .busy_timeout(BUSY_TIMEOUT)
+            .map_err(|err| AuditLogError::Storage(err.to_string()))?;
+
+        connection
+            .execute_batch(MIGRATIONS)
+            .map_err(|err| AuditLogError::Storage(err.to_string()))?;
+
+        let audit_log = Self {
+            connection: Mutex::new(connection),
+            time,
+        };
+
+        audit_log.verify_chain()?;
+
+        Ok(audit_log)
+    }
+
+    fn lock_connection(&self) -> Result<std::sync::MutexGuard<'_, Connection>, AuditLogError> {
+        self.connection
+            .lock()
+            .map_err(|_| AuditLogError::Storage("SQLite connection is poisoned".to_string()))
+    }
+
+    fn read_entries(&self) -> Result<Vec<AuditEntry>, AuditLogError> {
+        let connection = self.lock_connection()?;
+
+        let mut statement = connection
+            .prepare(
+                "SELECT sequence, action, actor, target, ip_address, user_agent, occurred_at,
+                        previous_hash, hash
+                 FROM audit_log ORDER BY sequence",
+            )
+            .map_err(|err| AuditLogError::Storage(err.to_string()))?;
+
+        let entries = statement
+            .query_map([], |row| {
+                Ok(AuditRow {
+                    sequence: row.get(0)?,
+                    action: row.get(1)?,
+                    actor: row.get(2)?,
+                    target: row.get(3)?,
+                    ip_address: row.get(4)?,
+                    user_agent: row.get(5)?,
+                    occurred_at: row.get(6)?,
+                    previous_hash: row.get(7)?,
+                    hash: row.get(8)?,
+                })
+            })
+            .map_err(|err| AuditLogError::Storage(err.to_string()))?
+            .map(|row| {
+                row.map_err(|err| AuditLogError::Storage(err.to_string()))?
+                    .try_into()
+            })
+            .collect();
+
+        drop(statement);
+        drop(connection);
+
+        entries
+    }
+}
+
+struct AuditRow {
+    sequence: i64,
+    action: String,
+    actor: Option<String>,
+    target: Option<String>,
+    ip_address: Option<String>,
+    user_agent: Option<String>,
+    occurred_at: i64,
+    previous_hash: String,
+    hash: String,
+}
+
+impl TryFrom<AuditRow> for AuditEntry {
+    type Error = AuditLogError;
+
+    fn try_from(row: AuditRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sequence: u64::try_from(row.sequence)
+                .map_err(|err| AuditLogError::Storage(err.to_string()))?,
+            action: row.action.parse()?,
+            actor: row.actor,
+            target: row.target,
+            ip_address: row.ip_address,
+            user_agent: row.user_agent,
+            occurred_at: row.occurred_at,
+            previous_hash: row.previous_hash,
+            hash: row.hash,
+        })
+    }
+}
+
+impl AuditLogPort for SqliteAuditLogAdapter {
+    fn record(&self, event: AuditEvent) -> Result<AuditEntry, AuditLogError> {
+        let mut connection = self.lock_connection()?;
+
+        let transaction = connection
+            .transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)
+            .map_err(|err| AuditLogError::Storage(err.to_string()))?;
+
+        let head = transaction
+            .query_row(
+                "SELECT sequence, hash FROM audit_log ORDER BY sequence DESC LIMIT 1",
+                [],
+                |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)),
+            )
+            .optional()
+            .map_err(|err| AuditLogError::Storage(err.to_string()))?;
+
+        let (sequence, previous_hash) = match head {
+            Some((sequence, hash)) => (
+                u64::try_from(sequence).map_err(|err| AuditLogError::Storage(err.to_string()))?,
+                hash,
+            ),
+            None => (0, GENESIS_HASH.to_string()),
+        };
+
+        let entry = chain::seal(
+            event,
+            sequence + 1,
+            self.time.now().unix_seconds(),
+            previous_hash,
+        );
+
+        transaction
+            .execute(
+                "INSERT INTO audit_log (sequence, action, actor, target, ip_address, user_agent,
+                                        occurred_at, previous_hash, hash)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    i64::try_from(entry.sequence)
+                        .map_err(|err| AuditLogError::Storage(err.to_string()))?,
+                    entry.action.as_str(),
+                    entry.actor,
+                    entry.target,
+                    entry.ip_address,
+                    entry.user_agent,
+                    entry.occurred_at,
+                    entry.previous_hash,
+                    entry.hash,
+                ],
+            )
+            .map_err(|err| AuditLogError::Storage(err.to_string()))?;
+
+        transaction
+            .commit()
+            .map_err(|err| AuditLogError::Storage(err.to_string()))?;
+
+        drop(connection);
+
+        Ok(entry)
+    }
+
+    fn query(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, AuditLogError> {
+        Ok(chain::select(self.read_entries()?, query))
+    }
+
+    fn verify_chain(&self) -> Result<(), AuditLogError> {
+        chain::verify(&self.read_entries()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rusqlite::Connection;
+
+    use crate::{
+        domain::values::timestamp::Timestamp,
+        infrastructure::adapters::audit_log::sqlite::SqliteAuditLogAdapter,
+        test_support::{contracts::audit_log::audit_log_contract, fakes::ManualClock},
+    };
+
+    audit_log_contract!(
+        |path| SqliteAuditLogAdapter::open(
+            path,
+            Arc::new(ManualClock::new(Timestamp::from_unix_seconds(1_000_000))),
+        ),
+        |path| {
+            Connection::open(path)
+                .unwrap()
+                .execute(
+                    "UPDATE audit_log SET actor = 'user_3' WHERE sequence = 2",
+                    [],
+                )
+                .unwrap();
+        }
+    );
+}
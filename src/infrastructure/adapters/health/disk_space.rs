@@ -0,0 +1,60 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+use crate::application::ports::adapters::health_check::{HealthCheckError, HealthCheckPort};
+
+const BYTES_PER_MEGABYTE: u64 = 1024 * 1024;
+
+/// Checks that the file system holding a file adapter's data still has room to write.
+pub struct DiskSpaceHealthCheck {
+    name: &'static str,
+    path: PathBuf,
+    min_free_bytes: u64,
+}
+
+impl DiskSpaceHealthCheck {
+    /// Watches the file system of `path`, which may be a file that does not exist yet.
+    #[must_use]
+    pub fn new(name: &'static str, path: impl Into<PathBuf>, min_free_megabytes: u32) -> Self {
+        Self {
+            name,
+            path: path.into(),
+            min_free_bytes: u64::from(min_free_megabytes) * BYTES_PER_MEGABYTE,
+        }
+    }
+
+    fn directory(&self) -> PathBuf {
+        match self.path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        }
+    }
+}
+
+#[async_trait]
+impl HealthCheckPort for DiskSpaceHealthCheck {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    async fn check(&self) -> Result<(), HealthCheckError> {
+        let directory = self.directory();
+        let min_free_bytes = self.min_free_bytes;
+
+        let available = tokio::task::spawn_blocking(move || fs4::available_space(directory))
+            .await
+            .map_err(|err| HealthCheckError::Unhealthy(err.to_string()))?
+            .map_err(|err| HealthCheckError::Unhealthy(err.to_string()))?;
+
+        if available < min_free_bytes {
+            return Err(HealthCheckError::Unhealthy(format!(
+                "only {} MB free, at least {} MB required",
+                available / BYTES_PER_MEGABYTE,
+                min_free_bytes / BYTES_PER_MEGABYTE
+            )));
+        }
+
+        Ok(())
+    }
+}
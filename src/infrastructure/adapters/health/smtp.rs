@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use tokio::net::TcpStream;
+
+use crate::application::ports::adapters::health_check::{HealthCheckError, HealthCheckPort};
+
+/// Checks that the SMTP relay accepts TCP connections. It does not log in, so a wrong password is
+/// only noticed when an e-mail is sent.
+pub struct SmtpHealthCheck {
+    host: String,
+    port: u16,
+}
+
+impl SmtpHealthCheck {
+    #[must_use]
+    pub const fn new(host: String, port: u16) -> Self {
+        Self { host, port }
+    }
+}
+
+#[async_trait]
+impl HealthCheckPort for SmtpHealthCheck {
+    fn name(&self) -> &'static str {
+        "mailer"
+    }
+
+    async fn check(&self) -> Result<(), HealthCheckError> {
+        TcpStream::connect((self.host.as_str(), self.port))
+            .await
+            .map(drop)
+            .map_err(|err| {
+                HealthCheckError::Unhealthy(format!(
+                    "could not reach {}:{}: {err}",
+                    self.host, self.port
+                ))
+            })
+    }
+}
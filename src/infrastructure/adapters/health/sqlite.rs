@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use rusqlite::{Connection, OpenFlags};
+
+use crate::{
+    application::ports::adapters::health_check::{HealthCheckError, HealthCheckPort},
+    infrastructure::repositories::sqlite::user::SCHEMA_VERSION,
+};
+
+/// Checks that the database file can be opened and answers a trivial query.
+pub struct SqlitePingHealthCheck {
+    path: String,
+}
+
+impl SqlitePingHealthCheck {
+    #[must_use]
+    pub const fn new(path: String) -> Self {
+        Self { path }
+    }
+}
+
+#[async_trait]
+impl HealthCheckPort for SqlitePingHealthCheck {
+    fn name(&self) -> &'static str {
+        "database"
+    }
+
+    async fn check(&self) -> Result<(), HealthCheckError> {
+        let path = self.path.clone();
+
+        run_blocking(move || {
+            open_existing(&path)?
+                .query_row("SELECT 1", [], |row| row.get::<_, i64>(0))
+                .map(|_| ())
+                .map_err(|err| HealthCheckError::Unhealthy(err.to_string()))
+        })
+        .await
+    }
+}
+
+/// Checks that the schema migrations have been applied up to the version this build expects.
+pub struct SqliteMigrationsHealthCheck {
+    path: String,
+}
+
+impl SqliteMigrationsHealthCheck {
+    #[must_use]
+    pub const fn new(path: String) -> Self {
+        Self { path }
+    }
+}
+
+#[async_trait]
+impl HealthCheckPort for SqliteMigrationsHealthCheck {
+    fn name(&self) -> &'static str {
+        "migrations"
+    }
+
+    async fn check(&self) -> Result<(), HealthCheckError> {
+        let path = self.path.clone();
+
+        run_blocking(move || {
+            let version = open_existing(&path)?
+                .query_row("PRAGMA user_version", [], |row| row.get::<_, i64>(0))
+                .map_err(|err| HealthCheckError::Unhealthy(err.to_string()))?;
+
+            if version < SCHEMA_VERSION {
+                return Err(HealthCheckError::Unhealthy(format!(
+                    "schema is at version {version}, expected {SCHEMA_VERSION}"
+                )));
+            }
+
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// Never creates the file, so a missing database is reported instead of silently replaced.
+fn open_existing(path: &str) -> Result<Connection, HealthCheckError> {
+    Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(|err| HealthCheckError::Unhealthy(err.to_string()))
+}
+
+async fn run_blocking(
+    check: impl FnOnce() -> Result<(), HealthCheckError> + Send + 'static,
+) -> Result<(), HealthCheckError> {
+    tokio::task::spawn_blocking(check)
+        .await
+        .map_err(|err| HealthCheckError::Unhealthy(err.to_string()))?
+}
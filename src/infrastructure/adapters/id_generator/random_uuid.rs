@@ -0,0 +1,36 @@
+use crate::{
+    application::ports::adapters::id_generator::{IdGeneratorPort, InvalidIdError},
+    infrastructure::adapters::id_generator::uuid::{format_uuid, parse_uuid},
+};
+
+/// Generates random version 4 UUIDs in their hyphenated lowercase form.
+pub struct RandomUuidAdapter;
+
+impl IdGeneratorPort for RandomUuidAdapter {
+    fn generate_id(&self) -> String {
+        let bits = rand::random::<u128>();
+        // Sets the version nibble to 4 and the variant bits to 10, as RFC 9562 requires.
+        let uuid = (bits & !(0xf << 76) & !(0b11 << 62)) | (0x4 << 76) | (0b10 << 62);
+
+        format_uuid(uuid)
+    }
+
+    fn parse_id(&self, value: &str) -> Result<String, InvalidIdError> {
+        parse_uuid(value, 4)
+            .map(format_uuid)
+            .ok_or_else(|| InvalidIdError {
+                value: value.to_string(),
+                expected: "UUID v4",
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        infrastructure::adapters::id_generator::random_uuid::RandomUuidAdapter,
+        test_support::contracts::id_generator::id_generator_contract,
+    };
+
+    id_generator_contract!(|| RandomUuidAdapter);
+}
@@ -0,0 +1,117 @@
+use std::sync::{Mutex, PoisonError};
+
+use crate::{
+    application::ports::adapters::id_generator::{IdGeneratorPort, InvalidIdError},
+    infrastructure::adapters::id_generator::clock::unix_millis,
+};
+
+/// The highest worker id that fits in the 10 bits a Snowflake id reserves for it.
+pub const MAX_WORKER_ID: u16 = 1023;
+
+/// 2020-01-01T00:00:00Z, where the 41-bit timestamp starts counting, which lasts until 2089.
+const EPOCH_MILLIS: u64 = 1_577_836_800_000;
+const SEQUENCE_MAX: u64 = 0xfff;
+const DIGITS: usize = 19;
+
+struct Head {
+    millis: u64,
+    sequence: u64,
+}
+
+/// Generates Snowflake ids: 64-bit integers made of a millisecond timestamp, the worker id and a
+/// per-millisecond sequence, so instances with distinct worker ids never collide.
+///
+/// Ids are zero-padded to 19 digits, so they sort by creation time as strings too. When the
+/// sequence runs out, the timestamp is moved on by a millisecond.
+pub struct SnowflakeAdapter {
+    worker_id: u64,
+    head: Mutex<Head>,
+}
+
+impl SnowflakeAdapter {
+    /// # Panics
+    ///
+    /// Panics if `worker_id` is greater than [`MAX_WORKER_ID`].
+    #[must_use]
+    pub fn new(worker_id: u16) -> Self {
+        assert!(
+            worker_id <= MAX_WORKER_ID,
+            "Snowflake worker id {worker_id} does not fit in 10 bits"
+        );
+
+        Self {
+            worker_id: u64::from(worker_id),
+            head: Mutex::new(Head {
+                millis: 0,
+                sequence: 0,
+            }),
+        }
+    }
+}
+
+impl IdGeneratorPort for SnowflakeAdapter {
+    fn generate_id(&self) -> String {
+        // The head is always left consistent, so a panic elsewhere does not make it unusable.
+        let mut head = self.head.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = unix_millis().saturating_sub(EPOCH_MILLIS);
+
+        if now > head.millis {
+            head.millis = now;
+            head.sequence = 0;
+        } else if head.sequence == SEQUENCE_MAX {
+            head.millis += 1;
+            head.sequence = 0;
+        } else {
+            head.sequence += 1;
+        }
+
+        let id = (head.millis & 0x1ff_ffff_ffff) << 22 | self.worker_id << 12 | head.sequence;
+
+        drop(head);
+
+        format!("{id:0DIGITS$}")
+    }
+
+    fn parse_id(&self, value: &str) -> Result<String, InvalidIdError> {
+        let is_well_formed =
+            value.len() == DIGITS && value.bytes().all(|byte| byte.is_ascii_digit());
+
+        value
+            .parse::<i64>()
+            .ok()
+            .filter(|id| is_well_formed && *id >= 0)
+            .map(|_| value.to_string())
+            .ok_or_else(|| InvalidIdError {
+                value: value.to_string(),
+                expected: "Snowflake id",
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::ports::adapters::id_generator::IdGeneratorPort,
+        infrastructure::adapters::id_generator::snowflake::SnowflakeAdapter,
+        test_support::contracts::id_generator::id_generator_contract,
+    };
+
+    id_generator_contract!(|| SnowflakeAdapter::new(42));
+
+    #[test]
+    fn should_generate_ids_sorting_in_creation_order() {
+        let generator = SnowflakeAdapter::new(42);
+        let ids = (0..10_000)
+            .map(|_| generator.generate_id())
+            .collect::<Vec<_>>();
+
+        assert!(ids.is_sorted());
+        assert!(
+            ids.iter()
+                .all(|id| { id.parse::<u64>().is_ok_and(|id| id >> 12 & 0x3ff == 42) })
+        );
+        assert!(generator.parse_id("0000000000000000042").is_ok());
+        assert!(generator.parse_id("42").is_err());
+        assert!(generator.parse_id("9999999999999999999").is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use std::sync::{Mutex, PoisonError};
+
+use crate::{
+    application::ports::adapters::id_generator::{IdGeneratorPort, InvalidIdError},
+    infrastructure::adapters::id_generator::clock::unix_millis,
+};
+
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const LENGTH: usize = 26;
+const RANDOM_BITS: u32 = 80;
+const RANDOM_MASK: u128 = (1 << RANDOM_BITS) - 1;
+
+struct Head {
+    millis: u64,
+    random: u128,
+}
+
+/// Generates ULIDs: a millisecond timestamp and 80 random bits, in 26 characters of Crockford's
+/// base 32, so ids sort by creation time both as strings and in indexes.
+///
+/// Ids generated within the same millisecond increment the random part of the previous one, as
+/// the specification's monotonic mode does.
+pub struct UlidAdapter {
+    head: Mutex<Head>,
+}
+
+impl UlidAdapter {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            head: Mutex::new(Head {
+                millis: 0,
+                random: 0,
+            }),
+        }
+    }
+}
+
+impl Default for UlidAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IdGeneratorPort for UlidAdapter {
+    fn generate_id(&self) -> String {
+        // The head is always left consistent, so a panic elsewhere does not make it unusable.
+        let mut head = self.head.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = unix_millis();
+
+        if now > head.millis {
+            head.millis = now;
+            head.random = rand::random::<u128>() & RANDOM_MASK;
+        } else if head.random == RANDOM_MASK {
+            head.millis += 1;
+            head.random = rand::random::<u128>() & RANDOM_MASK;
+        } else {
+            head.random += 1;
+        }
+
+        let ulid = (u128::from(head.millis) & 0xffff_ffff_ffff) << RANDOM_BITS | head.random;
+
+        drop(head);
+
+        encode(ulid)
+    }
+
+    fn parse_id(&self, value: &str) -> Result<String, InvalidIdError> {
+        decode(value).map(encode).ok_or_else(|| InvalidIdError {
+            value: value.to_string(),
+            expected: "ULID",
+        })
+    }
+}
+
+fn encode(ulid: u128) -> String {
+    (0..LENGTH)
+        .map(|index| {
+            let shift = 5 * (LENGTH - 1 - index);
+
+            char::from(ALPHABET[(ulid >> shift & 0x1f) as usize])
+        })
+        .collect()
+}
+
+/// Reads a ULID in either case. The first character only carries 3 bits, so anything above `7`
+/// would overflow 128 bits.
+fn decode(value: &str) -> Option<u128> {
+    if value.len() != LENGTH || !value.starts_with(|first: char| ('0'..='7').contains(&first)) {
+        return None;
+    }
+
+    value.bytes().try_fold(0u128, |ulid, byte| {
+        let digit = ALPHABET
+            .iter()
+            .position(|&candidate| candidate == byte.to_ascii_uppercase())?;
+
+        Some(ulid << 5 | digit as u128)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::ports::adapters::id_generator::IdGeneratorPort,
+        infrastructure::adapters::id_generator::ulid::UlidAdapter,
+        test_support::contracts::id_generator::id_generator_contract,
+    };
+
+    id_generator_contract!(UlidAdapter::new);
+
+    #[test]
+    fn should_generate_ids_sorting_in_creation_order() {
+        let generator = UlidAdapter::new();
+        let ids = (0..10_000)
+            .map(|_| generator.generate_id())
+            .collect::<Vec<_>>();
+
+        assert!(ids.is_sorted());
+        assert!(
+            generator
+                .parse_id("01arz3ndektsv4rrffq69g5fav")
+                .is_ok_and(|id| id == "01ARZ3NDEKTSV4RRFFQ69G5FAV")
+        );
+        assert!(generator.parse_id("81ARZ3NDEKTSV4RRFFQ69G5FAV").is_err());
+        assert!(generator.parse_id("01ARZ3NDEKTSV4RRFFQ69G5FAU").is_err());
+    }
+}
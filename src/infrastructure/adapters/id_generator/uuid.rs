@@ -0,0 +1,33 @@
+/// Renders `uuid` in its hyphenated lowercase form.
+#[must_use]
+pub fn format_uuid(uuid: u128) -> String {
+    let hex = format!("{uuid:032x}");
+
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// Reads a hyphenated UUID, in either case, if it has the given `version` and the variant RFC 9562
+/// defines.
+#[must_use]
+pub fn parse_uuid(value: &str, version: u8) -> Option<u128> {
+    let is_well_formed = value.len() == 36
+        && value.char_indices().all(|(index, character)| match index {
+            8 | 13 | 18 | 23 => character == '-',
+            _ => character.is_ascii_hexdigit(),
+        });
+
+    if !is_well_formed {
+        return None;
+    }
+
+    let uuid = u128::from_str_radix(&value.replace('-', ""), 16).ok()?;
+
+    (uuid >> 76 & 0xf == u128::from(version) && uuid >> 62 & 0b11 == 0b10).then_some(uuid)
+}
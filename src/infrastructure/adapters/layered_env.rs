@@ -0,0 +1,471 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use zeroize::Zeroize;
+
+use crate::application::ports::adapters::env::{ConfigSource, EnvError, EnvPort, ResolvedValue};
+
+/// Where the layers are read from. Kept apart from the adapter so tests can supply their own
+/// environment and arguments instead of the process ones.
+///
+/// The environment and arguments may hold secrets, so they are zeroized on drop.
+#[derive(Clone)]
+pub struct LayeredEnvOptions {
+    pub config_dir: PathBuf,
+    pub environment: Vec<(String, String)>,
+    pub args: Vec<String>,
+}
+
+impl LayeredEnvOptions {
+    #[must_use]
+    pub fn from_process(args: Vec<String>) -> Self {
+        Self {
+            config_dir: PathBuf::from("."),
+            environment: std::env::vars().collect(),
+            args,
+        }
+    }
+
+    /// The files the adapter reads, lowest precedence first, whether they exist or not.
+    #[must_use]
+    pub fn file_paths(&self) -> Vec<PathBuf> {
+        let flags = parse_flags(&self.args).unwrap_or_default();
+        let mut file_names = vec!["config.toml".to_string()];
+
+        if let Some(profile) = self.profile(&flags) {
+            file_names.push(format!("config.{profile}.toml"));
+        }
+
+        file_names.push(".env".to_string());
+
+        file_names
+            .into_iter()
+            .map(|file_name| self.config_dir.join(file_name))
+            .collect()
+    }
+
+    fn profile(&self, flags: &HashMap<String, String>) -> Option<String> {
+        flags.get("PROFILE").cloned().or_else(|| {
+            self.environment
+                .iter()
+                .find(|(key, _)| key == "APP_PROFILE")
+                .map(|(_, value)| value.clone())
+        })
+    }
+}
+
+impl Drop for LayeredEnvOptions {
+    fn drop(&mut self) {
+        for (key, value) in &mut self.environment {
+            key.zeroize();
+            value.zeroize();
+        }
+
+        self.args.zeroize();
+    }
+}
+
+/// The values of one source. They may hold secrets, so they are zeroized on drop: an adapter
+/// only keeps them in memory for as long as the configuration is being loaded.
+struct Layer {
+    source: ConfigSource,
+    values: HashMap<String, String>,
+}
+
+impl Drop for Layer {
+    fn drop(&mut self) {
+        for (mut key, mut value) in self.values.drain() {
+            key.zeroize();
+            value.zeroize();
+        }
+    }
+}
+
+/// Resolves configuration keys through layered sources.
+///
+/// By increasing precedence: `config.toml`, `config.<profile>.toml`, `.env`, the process
+/// environment and `--some-key value` command-line flags. The profile comes from the `--profile`
+/// flag or the `APP_PROFILE` variable.
+pub struct LayeredEnvAdapter {
+    options: LayeredEnvOptions,
+    layers: Option<Vec<Layer>>,
+}
+
+impl LayeredEnvAdapter {
+    #[must_use]
+    pub const fn new(options: LayeredEnvOptions) -> Self {
+        Self {
+            options,
+            layers: None,
+        }
+    }
+
+    fn read_toml(&self, file_name: &str) -> Result<Option<HashMap<String, String>>, EnvError> {
+        let path = self.options.config_dir.join(file_name);
+
+        let mut contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => {
+                return Err(EnvError::FileParsing {
+                    path: path.display().to_string(),
+                    reason: err.to_string(),
+                });
+            }
+        };
+
+        let table = toml::from_str::<toml::Table>(&contents);
+
+        contents.zeroize();
+
+        let table = table.map_err(|err| EnvError::FileParsing {
+            path: path.display().to_string(),
+            reason: err.to_string(),
+        })?;
+
+        let mut values = HashMap::new();
+
+        flatten_toml("", &table, &mut values);
+
+        Ok(Some(values))
+    }
+
+    fn read_dotenv(&self) -> Result<Option<HashMap<String, String>>, EnvError> {
+        let path = self.options.config_dir.join(".env");
+
+        let to_parsing_error = |err: dotenvy::Error| EnvError::FileParsing {
+            path: path.display().to_string(),
+            reason: err.to_string(),
+        };
+
+        let iter = match dotenvy::from_path_iter(&path) {
+            Ok(iter) => iter,
+            Err(dotenvy::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(None);
+            }
+            Err(err) => return Err(to_parsing_error(err)),
+        };
+
+        iter.collect::<Result<HashMap<_, _>, _>>()
+            .map(Some)
+            .map_err(to_parsing_error)
+    }
+}
+
+impl EnvPort for LayeredEnvAdapter {
+    fn load_env_file(&mut self) -> Result<(), EnvError> {
+        let flags = parse_flags(&self.options.args)?;
+        let environment = self
+            .options
+            .environment
+            .iter()
+            .cloned()
+            .collect::<HashMap<_, _>>();
+
+        let profile = self.options.profile(&flags);
+
+        let mut layers = Vec::new();
+
+        if let Some(values) = self.read_toml("config.toml")? {
+            layers.push(Layer {
+                source: ConfigSource::ConfigFile("config.toml".to_string()),
+                values,
+            });
+        }
+
+        if let Some(profile) = profile {
+            let file_name = format!("config.{profile}.toml");
+
+            if let Some(values) = self.read_toml(&file_name)? {
+                layers.push(Layer {
+                    source: ConfigSource::ProfileFile(file_name),
+                    values,
+                });
+            }
+        }
+
+        if let Some(values) = self.read_dotenv()? {
+            layers.push(Layer {
+                source: ConfigSource::DotEnv(".env".to_string()),
+                values,
+            });
+        }
+
+        layers.push(Layer {
+            source: ConfigSource::Environment,
+            values: environment,
+        });
+
+        layers.push(Layer {
+            source: ConfigSource::CommandLine,
+            values: flags,
+        });
+
+        self.layers = Some(layers);
+
+        Ok(())
+    }
+
+    fn lookup(&self, key: &str) -> Result<Option<ResolvedValue>, EnvError> {
+        let layers = self.layers.as_ref().ok_or(EnvError::FileNotLoaded)?;
+        let file_key = format!("{key}_FILE");
+
+        for layer in layers.iter().rev() {
+            if let Some(value) = layer.values.get(key) {
+                return Ok(Some(ResolvedValue {
+                    value: value.clone(),
+                    source: layer.source.clone(),
+                }));
+            }
+
+            if let Some(path) = layer.values.get(&file_key) {
+                return read_secret_file(path).map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Follows the `<KEY>_FILE` convention of Docker and Kubernetes secrets, where the value is
+/// mounted as a file whose trailing newline is not part of it.
+fn read_secret_file(path: &str) -> Result<ResolvedValue, EnvError> {
+    let mut contents =
+        std::fs::read_to_string(path).map_err(|err| EnvError::SecretFileUnreadable {
+            path: path.to_string(),
+            reason: err.to_string(),
+        })?;
+
+    let value = contents.trim_end_matches(['\r', '\n']).to_string();
+
+    contents.zeroize();
+
+    Ok(ResolvedValue {
+        value,
+        source: ConfigSource::SecretFile(path.to_string()),
+    })
+}
+
+/// Maps `[server] port = 8080` to `SERVER_PORT=8080`, so every layer shares the same keys. Nested
+/// tables keep joining with `_`, and arrays become comma-separated lists.
+fn flatten_toml(prefix: &str, table: &toml::Table, values: &mut HashMap<String, String>) {
+    for (name, value) in table {
+        let key = if prefix.is_empty() {
+            name.to_uppercase()
+        } else {
+            format!("{prefix}_{}", name.to_uppercase())
+        };
+
+        match value {
+            toml::Value::Table(table) => flatten_toml(&key, table, values),
+            toml::Value::String(value) => {
+                values.insert(key, value.clone());
+            }
+            toml::Value::Array(items) => {
+                let items = items
+                    .iter()
+                    .map(|item| {
+                        item.as_str()
+                            .map_or_else(|| item.to_string(), str::to_string)
+                    })
+                    .collect::<Vec<_>>();
+
+                values.insert(key, items.join(","));
+            }
+            value => {
+                values.insert(key, value.to_string());
+            }
+        }
+    }
+}
+
+/// Maps `--server-port 8080` and `--server-port=8080` to `SERVER_PORT=8080`.
+fn parse_flags(args: &[String]) -> Result<HashMap<String, String>, EnvError> {
+    let mut flags = HashMap::new();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let flag = arg
+            .strip_prefix("--")
+            .filter(|flag| !flag.is_empty())
+            .ok_or_else(|| EnvError::InvalidArgument(arg.clone()))?;
+
+        let (name, value) = match flag.split_once('=') {
+            Some((name, value)) => (name, value.to_string()),
+            None => (
+                flag,
+                args.next()
+                    .ok_or_else(|| EnvError::InvalidArgument(arg.clone()))?
+                    .clone(),
+            ),
+        };
+
+        flags.insert(name.replace('-', "_").to_uppercase(), value);
+    }
+
+    Ok(flags)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::ports::adapters::env::{ConfigSource, EnvError, EnvPort, ResolvedValue},
+        infrastructure::adapters::layered_env::{LayeredEnvAdapter, LayeredEnvOptions},
+    };
+
+    fn config_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "axum_tdd_api_layered_env_{name}_{}",
+            std::process::id()
+        ));
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        std::fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    fn resolved(value: &str, source: ConfigSource) -> ResolvedValue {
+        ResolvedValue {
+            value: value.to_string(),
+            source,
+        }
+    }
+
+    #[test]
+    fn should_resolve_each_key_from_the_highest_precedence_layer() {
+        let dir = config_dir("precedence");
+
+        std::fs::write(
+            dir.join("config.toml"),
+            "[server]\nhost = \"0.0.0.0\"\nport = 3000\n\n[log]\nlevel = \"debug\"\nformat = \"json\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("config.production.toml"),
+            "[server]\nport = 4000\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join(".env"),
+            "LOG_LEVEL=warn\nDATABASE_PATH=dotenv.db\n",
+        )
+        .unwrap();
+
+        let mut adapter = LayeredEnvAdapter::new(LayeredEnvOptions {
+            config_dir: dir.clone(),
+            environment: vec![
+                ("APP_PROFILE".to_string(), "production".to_string()),
+                ("DATABASE_PATH".to_string(), "environment.db".to_string()),
+            ],
+            args: vec!["--log-level=error".to_string()],
+        });
+
+        adapter.load_env_file().unwrap();
+
+        assert_eq!(
+            adapter.lookup("SERVER_HOST").unwrap(),
+            Some(resolved(
+                "0.0.0.0",
+                ConfigSource::ConfigFile("config.toml".to_string())
+            ))
+        );
+        assert_eq!(
+            adapter.lookup("SERVER_PORT").unwrap(),
+            Some(resolved(
+                "4000",
+                ConfigSource::ProfileFile("config.production.toml".to_string())
+            ))
+        );
+        assert_eq!(
+            adapter.lookup("LOG_FORMAT").unwrap(),
+            Some(resolved(
+                "json",
+                ConfigSource::ConfigFile("config.toml".to_string())
+            ))
+        );
+        assert_eq!(
+            adapter.lookup("DATABASE_PATH").unwrap(),
+            Some(resolved("environment.db", ConfigSource::Environment))
+        );
+        assert_eq!(
+            adapter.lookup("LOG_LEVEL").unwrap(),
+            Some(resolved("error", ConfigSource::CommandLine))
+        );
+        assert_eq!(adapter.lookup("MAIL_FROM").unwrap(), None);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn should_read_value_from_file_named_by_file_suffixed_key() {
+        let dir = config_dir("secret_file");
+        let secret_path = dir.join("admin_api_token");
+
+        std::fs::write(&secret_path, "SuperSecret123\n").unwrap();
+        std::fs::write(dir.join(".env"), "ADMIN_API_TOKEN=from-dotenv\n").unwrap();
+
+        let mut adapter = LayeredEnvAdapter::new(LayeredEnvOptions {
+            config_dir: dir.clone(),
+            environment: vec![
+                (
+                    "ADMIN_API_TOKEN_FILE".to_string(),
+                    secret_path.display().to_string(),
+                ),
+                (
+                    "MAIL_SMTP_PASSWORD_FILE".to_string(),
+                    dir.join("missing").display().to_string(),
+                ),
+            ],
+            args: Vec::new(),
+        });
+
+        adapter.load_env_file().unwrap();
+
+        assert_eq!(
+            adapter.lookup("ADMIN_API_TOKEN").unwrap(),
+            Some(resolved(
+                "SuperSecret123",
+                ConfigSource::SecretFile(secret_path.display().to_string())
+            ))
+        );
+        assert!(matches!(
+            adapter.lookup("MAIL_SMTP_PASSWORD"),
+            Err(EnvError::SecretFileUnreadable { .. })
+        ));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn should_skip_missing_files_and_reject_malformed_flags() {
+        let dir = config_dir("missing");
+
+        let mut adapter = LayeredEnvAdapter::new(LayeredEnvOptions {
+            config_dir: dir.clone(),
+            environment: vec![("SERVER_PORT".to_string(), "8080".to_string())],
+            args: vec!["--server-host".to_string(), "127.0.0.1".to_string()],
+        });
+
+        adapter.load_env_file().unwrap();
+
+        assert_eq!(adapter.get_env_var::<u16>("SERVER_PORT").unwrap(), 8080);
+        assert_eq!(
+            adapter.get_env_var::<String>("SERVER_HOST").unwrap(),
+            "127.0.0.1"
+        );
+
+        let mut adapter = LayeredEnvAdapter::new(LayeredEnvOptions {
+            config_dir: dir.clone(),
+            environment: Vec::new(),
+            args: vec!["--server-host".to_string()],
+        });
+
+        assert!(matches!(
+            adapter.load_env_file(),
+            Err(EnvError::InvalidArgument(arg)) if arg == "--server-host"
+        ));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
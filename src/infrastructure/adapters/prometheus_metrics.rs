@@ -0,0 +1,301 @@
+use std::{collections::BTreeMap, fmt::Write, sync::Mutex, time::Duration};
+
+use crate::application::ports::adapters::metrics::{MetricsPort, SignUpOutcome};
+
+/// Upper bounds, in seconds, of the latency histogram buckets. Prometheus' own defaults.
+const BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+const HTTP_REQUESTS: &str = "http_requests_total";
+const HTTP_REQUEST_DURATION: &str = "http_request_duration_seconds";
+const API_REQUESTS: &str = "api_requests_total";
+const SIGN_UPS: &str = "sign_ups_total";
+const PASSWORD_HASH_DURATION: &str = "password_hash_duration_seconds";
+const REPOSITORY_CALL_DURATION: &str = "repository_call_duration_seconds";
+const RATE_LIMIT_STORE_FAILURES: &str = "rate_limit_store_failures_total";
+
+/// Every metric family, in exposition order.
+const FAMILIES: [(&str, Kind, &str); 7] = [
+    (
+        HTTP_REQUESTS,
+        Kind::Counter,
+        "HTTP requests handled, by method, matched route and status",
+    ),
+    (
+        HTTP_REQUEST_DURATION,
+        Kind::Histogram,
+        "Time spent handling HTTP requests, by method, matched route and status",
+    ),
+    (API_REQUESTS, Kind::Counter, "API requests, by API version"),
+    (SIGN_UPS, Kind::Counter, "Sign-up attempts, by outcome"),
+    (
+        PASSWORD_HASH_DURATION,
+        Kind::Histogram,
+        "Time spent hashing passwords",
+    ),
+    (
+        REPOSITORY_CALL_DURATION,
+        Kind::Histogram,
+        "Time spent in repository calls, by operation",
+    ),
+    (
+        RATE_LIMIT_STORE_FAILURES,
+        Kind::Counter,
+        "Requests let through unchecked because the rate limit store failed, by policy",
+    ),
+];
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Counter,
+    Histogram,
+}
+
+impl Kind {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Counter => "counter",
+            Self::Histogram => "histogram",
+        }
+    }
+}
+
+type Labels = Vec<(&'static str, String)>;
+
+#[derive(Default)]
+struct Histogram {
+    /// Observations per bucket of [`BUCKETS`], not cumulative; the cumulative counts Prometheus
+    /// expects are computed when rendering.
+    buckets: [u64; BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, seconds: f64) {
+        if let Some(bucket) = BUCKETS.iter().position(|bound| seconds <= *bound) {
+            self.buckets[bucket] += 1;
+        }
+
+        self.sum += seconds;
+        self.count += 1;
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    counters: BTreeMap<(&'static str, Labels), u64>,
+    histograms: BTreeMap<(&'static str, Labels), Histogram>,
+}
+
+/// Keeps every metric in memory and renders them in the Prometheus text exposition format.
+pub struct PrometheusMetricsAdapter {
+    registry: Mutex<Registry>,
+}
+
+impl PrometheusMetricsAdapter {
+    /// Creates the registry with every sign-up outcome at zero, so rates can be computed from the
+    /// first scrape on.
+    #[must_use]
+    pub fn new() -> Self {
+        let mut registry = Registry::default();
+
+        for outcome in SignUpOutcome::ALL {
+            registry.counters.insert(
+                (SIGN_UPS, vec![("outcome", outcome.as_str().to_string())]),
+                0,
+            );
+        }
+
+        Self {
+            registry: Mutex::new(registry),
+        }
+    }
+
+    /// Renders every metric recorded so far.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a thread panicked while recording a metric.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let registry = self.registry.lock().expect("metrics registry is poisoned");
+        let mut output = String::new();
+
+        for (family, kind, help) in FAMILIES {
+            let _ = writeln!(output, "# HELP {family} {help}");
+            let _ = writeln!(output, "# TYPE {family} {}", kind.as_str());
+
+            for ((_, labels), value) in registry
+                .counters
+                .iter()
+                .filter(|((name, _), _)| *name == family)
+            {
+                let _ = writeln!(output, "{family}{} {value}", format_labels(labels, None));
+            }
+
+            for ((_, labels), histogram) in registry
+                .histograms
+                .iter()
+                .filter(|((name, _), _)| *name == family)
+            {
+                let mut cumulative = 0;
+
+                for (bound, count) in BUCKETS.iter().zip(histogram.buckets) {
+                    cumulative += count;
+
+                    let _ = writeln!(
+                        output,
+                        "{family}_bucket{} {cumulative}",
+                        format_labels(labels, Some(&bound.to_string()))
+                    );
+                }
+
+                let _ = writeln!(
+                    output,
+                    "{family}_bucket{} {}",
+                    format_labels(labels, Some("+Inf")),
+                    histogram.count
+                );
+                let _ = writeln!(
+                    output,
+                    "{family}_sum{} {}",
+                    format_labels(labels, None),
+                    histogram.sum
+                );
+                let _ = writeln!(
+                    output,
+                    "{family}_count{} {}",
+                    format_labels(labels, None),
+                    histogram.count
+                );
+            }
+        }
+
+        drop(registry);
+
+        output
+    }
+
+    fn increment(&self, name: &'static str, labels: Labels) {
+        if let Ok(mut registry) = self.registry.lock() {
+            *registry.counters.entry((name, labels)).or_default() += 1;
+        }
+    }
+
+    fn observe(&self, name: &'static str, labels: Labels, duration: Duration) {
+        if let Ok(mut registry) = self.registry.lock() {
+            registry
+                .histograms
+                .entry((name, labels))
+                .or_default()
+                .observe(duration.as_secs_f64());
+        }
+    }
+}
+
+impl Default for PrometheusMetricsAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetricsPort for PrometheusMetricsAdapter {
+    fn observe_http_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        let labels = vec![
+            ("method", method.to_string()),
+            ("route", route.to_string()),
+            ("status", status.to_string()),
+        ];
+
+        self.increment(HTTP_REQUESTS, labels.clone());
+        self.observe(HTTP_REQUEST_DURATION, labels, duration);
+    }
+
+    fn count_api_request(&self, version: &'static str) {
+        self.increment(API_REQUESTS, vec![("version", version.to_string())]);
+    }
+
+    fn count_sign_up(&self, outcome: SignUpOutcome) {
+        self.increment(SIGN_UPS, vec![("outcome", outcome.as_str().to_string())]);
+    }
+
+    fn observe_password_hash(&self, duration: Duration) {
+        self.observe(PASSWORD_HASH_DURATION, Vec::new(), duration);
+    }
+
+    fn observe_repository_call(&self, operation: &'static str, duration: Duration) {
+        self.observe(
+            REPOSITORY_CALL_DURATION,
+            vec![("operation", operation.to_string())],
+            duration,
+        );
+    }
+
+    fn count_rate_limit_store_failure(&self, policy: &'static str) {
+        self.increment(
+            RATE_LIMIT_STORE_FAILURES,
+            vec![("policy", policy.to_string())],
+        );
+    }
+}
+
+fn format_labels(labels: &Labels, le: Option<&str>) -> String {
+    let pairs = labels
+        .iter()
+        .map(|(name, value)| (*name, value.as_str()))
+        .chain(le.map(|le| ("le", le)))
+        .map(|(name, value)| format!("{name}=\"{}\"", escape_label_value(value)))
+        .collect::<Vec<_>>();
+
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", pairs.join(","))
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        application::ports::adapters::metrics::{MetricsPort, SignUpOutcome},
+        infrastructure::adapters::prometheus_metrics::PrometheusMetricsAdapter,
+    };
+
+    #[test]
+    fn should_render_counters_and_cumulative_histograms() {
+        let metrics = PrometheusMetricsAdapter::new();
+
+        metrics.observe_http_request("GET", "/health/live", 200, Duration::from_millis(3));
+        metrics.observe_http_request("GET", "/health/live", 200, Duration::from_millis(30));
+        metrics.count_sign_up(SignUpOutcome::UserAlreadyExists);
+
+        let output = metrics.render();
+
+        assert!(output.contains("# TYPE http_requests_total counter\n"));
+        assert!(output.contains(
+            "http_requests_total{method=\"GET\",route=\"/health/live\",status=\"200\"} 2\n"
+        ));
+        assert!(output.contains(
+            "http_request_duration_seconds_bucket{method=\"GET\",route=\"/health/live\",status=\"200\",le=\"0.005\"} 1\n"
+        ));
+        assert!(output.contains(
+            "http_request_duration_seconds_bucket{method=\"GET\",route=\"/health/live\",status=\"200\",le=\"0.05\"} 2\n"
+        ));
+        assert!(output.contains(
+            "http_request_duration_seconds_count{method=\"GET\",route=\"/health/live\",status=\"200\"} 2\n"
+        ));
+        assert!(output.contains("sign_ups_total{outcome=\"user_already_exists\"} 1\n"));
+        assert!(output.contains("sign_ups_total{outcome=\"success\"} 0\n"));
+    }
+}
@@ -0,0 +1,48 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use crate::application::ports::adapters::rate_limit::{
+    Quota, RateLimitDecision, RateLimitError, RateLimitStorePort,
+};
+
+/// Entries are pruned once the map grows past this many keys.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Keeps the rate limiting state in this process, so each instance enforces its own quota.
+#[derive(Default)]
+pub struct InMemoryRateLimitStore {
+    arrival_times: Mutex<HashMap<String, i64>>,
+}
+
+impl InMemoryRateLimitStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RateLimitStorePort for InMemoryRateLimitStore {
+    fn acquire(
+        &self,
+        key: &str,
+        quota: Quota,
+        now_ms: i64,
+    ) -> Result<RateLimitDecision, RateLimitError> {
+        let mut arrival_times = self
+            .arrival_times
+            .lock()
+            .map_err(|_| RateLimitError::Storage("rate limit state is poisoned".to_string()))?;
+
+        // A key whose arrival time has passed is indistinguishable from a key never seen.
+        if arrival_times.len() >= PRUNE_THRESHOLD {
+            arrival_times.retain(|_, tat_ms| *tat_ms > now_ms);
+        }
+
+        let (decision, tat_ms) = quota.apply(arrival_times.get(key).copied(), now_ms);
+
+        arrival_times.insert(key.to_string(), tat_ms);
+
+        drop(arrival_times);
+
+        Ok(decision)
+    }
+}
@@ -0,0 +1,196 @@
+use std::{sync::Mutex, time::Duration};
+
+use rusqlite::{Connection, OptionalExtension, TransactionBehavior, params};
+
+use crate::application::ports::adapters::rate_limit::{
+    Quota, RateLimitDecision, RateLimitError, RateLimitStorePort,
+};
+
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+const MIGRATIONS: &str = "
+    CREATE TABLE IF NOT EXISTS rate_limits (
+        key TEXT PRIMARY KEY NOT NULL,
+        tat_ms INTEGER NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS rate_limits_tat_ms ON rate_limits (tat_ms);
+";
+
+/// Keeps the rate limiting state in a `rate_limits` table, so every instance sharing the
+/// database counts against the same quota.
+///
+/// Rows whose arrival time has passed are deleted as requests come in, so the table only holds
+/// the keys still being limited.
+pub struct SqliteRateLimitStore {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteRateLimitStore {
+    /// Opens the database at `path` and makes sure the `rate_limits` table exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RateLimitError::Storage`] if the database cannot be opened or migrated.
+    pub fn open(path: &str) -> Result<Self, RateLimitError> {
+        let connection =
+            Connection::open(path).map_err(|err| RateLimitError::Storage(err.to_string()))?;
+
+        connection
+            .busy_timeout(BUSY_TIMEOUT)
+            .map_err(|err| RateLimitError::Storage(err.to_string()))?;
+        connection
+            .execute_batch(MIGRATIONS)
+            .map_err(|err| RateLimitError::Storage(err.to_string()))?;
+
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+}
+
+impl RateLimitStorePort for SqliteRateLimitStore {
+    fn acquire(
+        &self,
+        key: &str,
+        quota: Quota,
+        now_ms: i64,
+    ) -> Result<RateLimitDecision, RateLimitError> {
+        let mut connection = self
+            .connection
+            .lock()
+            .map_err(|_| RateLimitError::Storage("SQLite connection is poisoned".to_string()))?;
+
+        // An immediate transaction takes the write lock up front, so two instances cannot both
+        // read the same arrival time and let a request too many through.
+        let transaction = connection
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .map_err(|err| RateLimitError::Storage(err.to_string()))?;
+
+        // A key whose arrival time has passed is indistinguishable from a key never seen.
+        transaction
+            .execute(
+                "DELETE FROM rate_limits WHERE tat_ms <= ?1",
+                params![now_ms],
+            )
+            .map_err(|err| RateLimitError::Storage(err.to_string()))?;
+
+        let stored_tat_ms = transaction
+            .query_row(
+                "SELECT tat_ms FROM rate_limits WHERE key = ?1",
+                params![key],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|err| RateLimitError::Storage(err.to_string()))?;
+
+        let (decision, tat_ms) = quota.apply(stored_tat_ms, now_ms);
+
+        transaction
+            .execute(
+                "INSERT INTO rate_limits (key, tat_ms) VALUES (?1, ?2)
+                 ON CONFLICT (key) DO UPDATE SET tat_ms = excluded.tat_ms",
+                params![key, tat_ms],
+            )
+            .map_err(|err| RateLimitError::Storage(err.to_string()))?;
+        transaction
+            .commit()
+            .map_err(|err| RateLimitError::Storage(err.to_string()))?;
+
+        drop(connection);
+
+        Ok(decision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        application::ports::adapters::rate_limit::{Quota, RateLimitStorePort},
+        infrastructure::adapters::rate_limit::sqlite::SqliteRateLimitStore,
+    };
+
+    #[test]
+    fn should_share_quota_between_instances_using_same_database() {
+        let path =
+            std::env::temp_dir().join(format!("axum_tdd_api_rate_limit_{}.db", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        let _ = std::fs::remove_file(path);
+
+        let first = SqliteRateLimitStore::open(path).unwrap();
+        let second = SqliteRateLimitStore::open(path).unwrap();
+        let quota = Quota {
+            limit: 2,
+            period_seconds: 60,
+        };
+
+        assert!(
+            first
+                .acquire("sign_up:ip:1.2.3.4", quota, 0)
+                .unwrap()
+                .allowed
+        );
+        assert!(
+            second
+                .acquire("sign_up:ip:1.2.3.4", quota, 0)
+                .unwrap()
+                .allowed
+        );
+
+        let denied = first.acquire("sign_up:ip:1.2.3.4", quota, 0).unwrap();
+
+        assert!(!denied.allowed);
+        assert_eq!(denied.retry_after_seconds, Some(30));
+        assert!(
+            second
+                .acquire("sign_up:ip:5.6.7.8", quota, 0)
+                .unwrap()
+                .allowed
+        );
+        assert!(
+            second
+                .acquire("sign_up:ip:1.2.3.4", quota, 30_000)
+                .unwrap()
+                .allowed
+        );
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn should_delete_keys_whose_arrival_time_has_passed() {
+        let path = std::env::temp_dir().join(format!(
+            "axum_tdd_api_rate_limit_prune_{}.db",
+            std::process::id()
+        ));
+        let path = path.to_str().unwrap();
+
+        let _ = std::fs::remove_file(path);
+
+        let store = SqliteRateLimitStore::open(path).unwrap();
+        let quota = Quota {
+            limit: 2,
+            period_seconds: 60,
+        };
+
+        for key in ["sign_up:ip:1.2.3.4", "sign_up:ip:5.6.7.8"] {
+            assert!(store.acquire(key, quota, 0).unwrap().allowed);
+        }
+
+        assert!(
+            store
+                .acquire("sign_up:ip:9.9.9.9", quota, 30_000)
+                .unwrap()
+                .allowed
+        );
+
+        let keys: i64 = rusqlite::Connection::open(path)
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM rate_limits", [], |row| row.get(0))
+            .unwrap();
+
+        assert_eq!(keys, 1);
+
+        let _ = std::fs::remove_file(path);
+    }
+}
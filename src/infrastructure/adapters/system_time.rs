@@ -0,0 +1,40 @@
+use std::{
+    sync::LazyLock,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    application::ports::adapters::time::{MonotonicInstant, TimePort},
+    domain::values::timestamp::Timestamp,
+};
+
+static MONOTONIC_ORIGIN: LazyLock<Instant> = LazyLock::new(Instant::now);
+
+/// Reads the system's wall clock, to the millisecond, and its monotonic clock.
+pub struct SystemTimeAdapter;
+
+impl TimePort for SystemTimeAdapter {
+    fn now(&self) -> Timestamp {
+        Timestamp::from_unix_millis(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| {
+                    i64::try_from(elapsed.as_millis()).unwrap_or(i64::MAX)
+                }),
+        )
+    }
+
+    fn monotonic_now(&self) -> MonotonicInstant {
+        MonotonicInstant::from_origin(MONOTONIC_ORIGIN.elapsed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        infrastructure::adapters::system_time::SystemTimeAdapter,
+        test_support::contracts::time::time_contract,
+    };
+
+    time_contract!(|| SystemTimeAdapter);
+}
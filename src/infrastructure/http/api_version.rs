@@ -0,0 +1,39 @@
+use crate::domain::values::timestamp::Timestamp;
+
+/// A version of the public API, served under its own path prefix.
+///
+/// Versions share the use cases; each one maps requests and responses to its own wire format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiVersion {
+    V1,
+    V2,
+}
+
+impl ApiVersion {
+    pub const ALL: [Self; 2] = [Self::V1, Self::V2];
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::V1 => "v1",
+            Self::V2 => "v2",
+        }
+    }
+
+    /// Where the version's routes are nested, such as `/v1`.
+    #[must_use]
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::V1 => "/v1",
+            Self::V2 => "/v2",
+        }
+    }
+}
+
+/// When an API version is deprecated and when it stops being served, as announced to its
+/// clients. Past the sunset, requests to the version are answered `410 Gone`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ApiVersionPolicy {
+    pub deprecated_at: Option<Timestamp>,
+    pub sunset_at: Option<Timestamp>,
+}
@@ -0,0 +1,103 @@
+use std::{str::FromStr, time::Duration};
+
+use crate::domain::values::timestamp::Timestamp;
+
+/// A [`Timestamp`] written as a UTC date and time in RFC 3339, with or without milliseconds, or
+/// as a bare date standing for its midnight, such as `2024-06-30`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateTime(pub Timestamp);
+
+impl FromStr for DateTime {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (date, time) = value.split_once('T').unwrap_or((value, "00:00:00Z"));
+        let [year, month, day] = fields(date, '-').ok_or(())?;
+        let time = time.strip_suffix('Z').ok_or(())?;
+        let (time, millis) = match time.split_once('.') {
+            Some((time, millis)) if millis.len() == 3 => (time, number::<u64>(millis).ok_or(())?),
+            Some(_) => return Err(()),
+            None => (time, 0),
+        };
+        let [hours, minutes, seconds] = fields(time, ':').ok_or(())?;
+
+        let days_in_month = match month {
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            1..=12 => 31,
+            _ => return Err(()),
+        };
+
+        if !(1..=days_in_month).contains(&day) || hours > 23 || minutes > 59 || seconds > 59 {
+            return Err(());
+        }
+
+        let time_of_day = u64::from((hours * 60 + minutes) * 60 + seconds) * 1000 + millis;
+
+        Ok(Self(
+            Timestamp::from_date(year.into(), month.into(), day.into())
+                .saturating_add(Duration::from_millis(time_of_day)),
+        ))
+    }
+}
+
+/// Splits `value` into three fixed-width numbers, such as the year, month and day of `2024-06-30`.
+fn fields(value: &str, separator: char) -> Option<[u32; 3]> {
+    let mut parts = value.split(separator);
+    let fields = [parts.next()?, parts.next()?, parts.next()?];
+    let widths = if separator == '-' {
+        [4, 2, 2]
+    } else {
+        [2, 2, 2]
+    };
+
+    if parts.next().is_some() || fields.iter().zip(widths).any(|(f, width)| f.len() != width) {
+        return None;
+    }
+
+    Some([number(fields[0])?, number(fields[1])?, number(fields[2])?])
+}
+
+fn number<T: FromStr>(digits: &str) -> Option<T> {
+    if digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        digits.parse().ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{domain::values::timestamp::Timestamp, infrastructure::http::date_time::DateTime};
+
+    #[test]
+    fn should_parse_dates_and_rfc3339_in_utc() {
+        for (value, millis) in [
+            ("1970-01-01", 0),
+            ("1969-12-31T23:59:59.999Z", -1),
+            ("2023-11-14T22:13:20Z", 1_700_000_000_000),
+            ("2000-02-29T00:00:00.123Z", 951_782_400_123),
+            ("9999-12-31T23:59:59.999Z", 253_402_300_799_999),
+        ] {
+            assert_eq!(
+                value.parse(),
+                Ok(DateTime(Timestamp::from_unix_millis(millis)))
+            );
+        }
+
+        for value in [
+            "",
+            "2023-11-14T22:13:20",
+            "2023-11-14T22:13:20+01:00",
+            "2023-02-29",
+            "2023-13-01",
+            "2023-11-14T24:00:00Z",
+            "2023-11-14T22:13:20.1Z",
+            "2023-1-14",
+            "+023-11-14",
+        ] {
+            assert!(value.parse::<DateTime>().is_err(), "{value} was accepted");
+        }
+    }
+}
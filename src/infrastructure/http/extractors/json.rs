@@ -0,0 +1,26 @@
+use axum::{
+    Json,
+    extract::{FromRequest, Request},
+    response::{IntoResponse, Response},
+};
+use serde::de::DeserializeOwned;
+
+use crate::infrastructure::http::problem::Problem;
+
+/// A JSON request body. Malformed bodies are answered with problem+json, like every other
+/// failure, instead of axum's plain text rejections.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonBody<T>(pub T);
+
+impl<T: DeserializeOwned, S: Send + Sync> FromRequest<S> for JsonBody<T> {
+    type Rejection = Response;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        Json::<T>::from_request(request, state)
+            .await
+            .map(|Json(value)| Self(value))
+            .map_err(|rejection| {
+                Problem::new(rejection.status(), rejection.body_text()).into_response()
+            })
+    }
+}
@@ -0,0 +1,33 @@
+use std::convert::Infallible;
+
+use axum::{
+    extract::{ConnectInfo, FromRequestParts},
+    http::{header::USER_AGENT, request::Parts},
+};
+
+use crate::{application::inputs::request::RequestMetadata, infrastructure::http::peer::PeerAddr};
+
+/// Who sent the request, as recorded by audit entries.
+///
+/// The client IP is absent when the router is driven without a listener, or over a Unix socket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientMetadata(pub RequestMetadata);
+
+impl<S: Send + Sync> FromRequestParts<S> for ClientMetadata {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self(RequestMetadata {
+            ip_address: parts
+                .extensions
+                .get::<ConnectInfo<PeerAddr>>()
+                .and_then(|ConnectInfo(peer)| peer.ip())
+                .map(|ip| ip.to_string()),
+            user_agent: parts
+                .headers
+                .get(USER_AGENT)
+                .and_then(|value| value.to_str().ok())
+                .map(ToString::to_string),
+        }))
+    }
+}
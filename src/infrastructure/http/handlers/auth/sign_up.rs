@@ -0,0 +1,45 @@
+use utoipa::IntoResponses;
+
+use crate::infrastructure::http::problem::Problem;
+
+/// The failures every version of sign-up answers with, documented once for all of them.
+#[derive(IntoResponses)]
+pub enum SignUpProblems {
+    #[response(
+        status = BAD_REQUEST,
+        description = "The body is not valid JSON",
+        content_type = "application/problem+json"
+    )]
+    BadRequest(Problem),
+    #[response(
+        status = CONFLICT,
+        description = "An user already exists with the given e-mail address",
+        content_type = "application/problem+json"
+    )]
+    Conflict(Problem),
+    #[response(
+        status = UNSUPPORTED_MEDIA_TYPE,
+        description = "The body is not declared as application/json",
+        content_type = "application/problem+json"
+    )]
+    UnsupportedMediaType(Problem),
+    #[response(
+        status = UNPROCESSABLE_ENTITY,
+        description = "A field is missing, or a password confirmation was sent that does not match",
+        content_type = "application/problem+json"
+    )]
+    UnprocessableEntity(Problem),
+    #[response(
+        status = TOO_MANY_REQUESTS,
+        description = "The client signed up too often",
+        content_type = "application/problem+json",
+        headers(("Retry-After" = u64, description = "Seconds to wait before trying again"))
+    )]
+    TooManyRequests(Problem),
+    #[response(
+        status = INTERNAL_SERVER_ERROR,
+        description = "The request failed; the problem carries the ids to report it with",
+        content_type = "application/problem+json"
+    )]
+    InternalServerError(Problem),
+}
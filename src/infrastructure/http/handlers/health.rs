@@ -0,0 +1,381 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tokio::sync::Mutex;
+
+use crate::application::ports::adapters::health_check::HealthCheckPort;
+
+const UP: &str = "up";
+const DOWN: &str = "down";
+
+/// What the probes share: the checks readiness aggregates, how long each may take and their
+/// results are reused, and whether the process is shutting down or has ever been ready.
+pub struct HealthState {
+    checks: Vec<Arc<dyn HealthCheckPort>>,
+    timeout: Duration,
+    cache_ttl: Duration,
+    cached: Mutex<Option<(Instant, HealthReport)>>,
+    is_shutting_down: Box<dyn Fn() -> bool + Send + Sync>,
+    started: AtomicBool,
+}
+
+impl HealthState {
+    /// Asks `is_shutting_down` on every readiness probe whether graceful shutdown has begun.
+    #[must_use]
+    pub fn new(
+        checks: Vec<Arc<dyn HealthCheckPort>>,
+        timeout: Duration,
+        cache_ttl: Duration,
+        is_shutting_down: impl Fn() -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            checks,
+            timeout,
+            cache_ttl,
+            cached: Mutex::new(None),
+            is_shutting_down: Box::new(is_shutting_down),
+            started: AtomicBool::new(false),
+        }
+    }
+
+    /// The last results if they are younger than the cache TTL, otherwise fresh ones. Probes
+    /// arriving while the checks run wait for them instead of running their own.
+    async fn checked(&self) -> HealthReport {
+        let mut cached = self.cached.lock().await;
+
+        if let Some((checked_at, report)) = cached.as_ref()
+            && checked_at.elapsed() < self.cache_ttl
+        {
+            return report.clone();
+        }
+
+        let report = self.run_checks().await;
+
+        *cached = Some((Instant::now(), report.clone()));
+
+        report
+    }
+
+    /// Runs every check concurrently, each bounded by the configured timeout, and reports them in
+    /// registration order.
+    async fn run_checks(&self) -> HealthReport {
+        let handles = self
+            .checks
+            .iter()
+            .map(|check| {
+                let check = check.clone();
+                let timeout = self.timeout;
+
+                tokio::spawn(async move {
+                    let started_at = Instant::now();
+                    let result = tokio::time::timeout(timeout, check.check()).await;
+                    let duration_ms =
+                        u64::try_from(started_at.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+                    let error = match result {
+                        Ok(Ok(())) => None,
+                        Ok(Err(err)) => Some(err.to_string()),
+                        Err(_) => Some(format!("timed out after {}ms", timeout.as_millis())),
+                    };
+
+                    if let Some(error) = &error {
+                        tracing::warn!(check = check.name(), error = %error, "Health check failed");
+                    }
+
+                    CheckReport {
+                        name: check.name(),
+                        status: if error.is_none() { UP } else { DOWN },
+                        duration_ms: Some(duration_ms),
+                        error,
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut checks = Vec::with_capacity(handles.len());
+
+        for (handle, check) in handles.into_iter().zip(&self.checks) {
+            checks.push(handle.await.unwrap_or_else(|err| {
+                tracing::error!(check = check.name(), error = %err, "Health check panicked");
+
+                CheckReport {
+                    name: check.name(),
+                    status: DOWN,
+                    duration_ms: None,
+                    error: Some(err.to_string()),
+                }
+            }));
+        }
+
+        HealthReport::from_checks(checks)
+    }
+}
+
+/// How much of the readiness and startup reports a listener is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthDetail {
+    /// The name and status of each check, for listeners anyone can reach.
+    StatusOnly,
+    /// Also how long each check took and why it failed, which can name paths and hosts.
+    Full,
+}
+
+/// The probes of one listener, all sharing the same checks and cached results.
+#[derive(Clone)]
+pub struct HealthProbes {
+    pub state: Arc<HealthState>,
+    pub detail: HealthDetail,
+}
+
+impl HealthProbes {
+    fn respond(&self, report: HealthReport) -> Response {
+        match self.detail {
+            HealthDetail::StatusOnly => report.status_only().into_response(),
+            HealthDetail::Full => report.into_response(),
+        }
+    }
+}
+
+#[derive(Clone, Serialize)]
+struct CheckReport {
+    name: &'static str,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Clone, Serialize)]
+struct HealthReport {
+    status: &'static str,
+    checks: Vec<CheckReport>,
+}
+
+impl HealthReport {
+    fn from_checks(checks: Vec<CheckReport>) -> Self {
+        let status = if checks.iter().all(|check| check.status == UP) {
+            UP
+        } else {
+            DOWN
+        };
+
+        Self { status, checks }
+    }
+
+    fn is_up(&self) -> bool {
+        self.status == UP
+    }
+
+    /// The report without anything but the name and status of each check; the details of failed
+    /// checks are logged when they run.
+    fn status_only(mut self) -> Self {
+        for check in &mut self.checks {
+            check.duration_ms = None;
+            check.error = None;
+        }
+
+        self
+    }
+}
+
+impl IntoResponse for HealthReport {
+    fn into_response(self) -> Response {
+        let status = if self.is_up() {
+            StatusCode::OK
+        } else {
+            StatusCode::SERVICE_UNAVAILABLE
+        };
+
+        (status, Json(self)).into_response()
+    }
+}
+
+/// Answers as long as the process can serve HTTP at all; dependencies are not consulted, so a
+/// database outage never gets the process restarted.
+pub async fn live() -> Response {
+    HealthReport::from_checks(Vec::new()).into_response()
+}
+
+/// Aggregates every registered check, and reports the process as unavailable as soon as graceful
+/// shutdown begins so no new traffic is routed to it.
+pub async fn ready(State(probes): State<HealthProbes>) -> Response {
+    if (probes.state.is_shutting_down)() {
+        return probes.respond(HealthReport::from_checks(vec![CheckReport {
+            name: "shutdown",
+            status: DOWN,
+            duration_ms: None,
+            error: Some("graceful shutdown in progress".to_string()),
+        }]));
+    }
+
+    probes.respond(probes.state.checked().await)
+}
+
+/// Runs the readiness checks until they pass once, then keeps answering healthy so slow startups
+/// are told apart from later failures.
+pub async fn startup(State(probes): State<HealthProbes>) -> Response {
+    if probes.state.started.load(Ordering::Acquire) {
+        return HealthReport::from_checks(Vec::new()).into_response();
+    }
+
+    let report = probes.state.checked().await;
+
+    if report.is_up() {
+        probes.state.started.store(true, Ordering::Release);
+    }
+
+    probes.respond(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicBool, AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+    use axum::{body::to_bytes, extract::State, http::StatusCode, response::Response};
+    use serde_json::{Value, json};
+
+    use crate::{
+        application::ports::adapters::health_check::{HealthCheckError, HealthCheckPort},
+        infrastructure::http::handlers::health::{
+            HealthDetail, HealthProbes, HealthState, ready, startup,
+        },
+    };
+
+    struct StubCheck {
+        name: &'static str,
+        delay: Duration,
+        result: Result<(), HealthCheckError>,
+        runs: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl HealthCheckPort for StubCheck {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn check(&self) -> Result<(), HealthCheckError> {
+            self.runs.fetch_add(1, Ordering::Relaxed);
+            tokio::time::sleep(self.delay).await;
+
+            self.result.clone()
+        }
+    }
+
+    async fn body(response: Response) -> Value {
+        serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await.unwrap()).unwrap()
+    }
+
+    fn without_durations(mut report: Value) -> Value {
+        for check in report["checks"].as_array_mut().unwrap() {
+            check.as_object_mut().unwrap().remove("duration_ms");
+        }
+
+        report
+    }
+
+    #[tokio::test]
+    async fn should_report_each_check_and_go_unready_during_shutdown() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let shutting_down = Arc::new(AtomicBool::new(false));
+        let is_shutting_down = shutting_down.clone();
+        let state = Arc::new(HealthState::new(
+            vec![
+                Arc::new(StubCheck {
+                    name: "database",
+                    delay: Duration::ZERO,
+                    result: Ok(()),
+                    runs: runs.clone(),
+                }),
+                Arc::new(StubCheck {
+                    name: "mailer",
+                    delay: Duration::from_secs(5),
+                    result: Ok(()),
+                    runs: runs.clone(),
+                }),
+                Arc::new(StubCheck {
+                    name: "disk",
+                    delay: Duration::ZERO,
+                    result: Err(HealthCheckError::Unhealthy("disk full".to_string())),
+                    runs: runs.clone(),
+                }),
+            ],
+            Duration::from_millis(50),
+            Duration::from_secs(30),
+            move || is_shutting_down.load(Ordering::Acquire),
+        ));
+        let internal = HealthProbes {
+            state: state.clone(),
+            detail: HealthDetail::Full,
+        };
+        let public = HealthProbes {
+            state,
+            detail: HealthDetail::StatusOnly,
+        };
+
+        let response = ready(State(internal.clone())).await;
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(
+            without_durations(body(response).await),
+            json!({
+                "status": "down",
+                "checks": [
+                    { "name": "database", "status": "up" },
+                    { "name": "mailer", "status": "down", "error": "timed out after 50ms" },
+                    { "name": "disk", "status": "down", "error": "disk full" },
+                ],
+            })
+        );
+
+        let response = ready(State(public.clone())).await;
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(
+            body(response).await,
+            json!({
+                "status": "down",
+                "checks": [
+                    { "name": "database", "status": "up" },
+                    { "name": "mailer", "status": "down" },
+                    { "name": "disk", "status": "down" },
+                ],
+            })
+        );
+        assert_eq!(
+            startup(State(public.clone())).await.status(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+        assert_eq!(runs.load(Ordering::Relaxed), 3);
+
+        shutting_down.store(true, Ordering::Release);
+
+        let response = ready(State(internal)).await;
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(
+            body(response).await["checks"][0]["error"],
+            "graceful shutdown in progress"
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode, Uri, header::HOST},
+    response::{IntoResponse, Redirect, Response},
+};
+
+const DEFAULT_HTTPS_PORT: u16 = 443;
+
+/// Sends the client to the same host and path over HTTPS, served on `https_port`.
+pub async fn redirect_to_https(
+    State(https_port): State<u16>,
+    headers: HeaderMap,
+    uri: Uri,
+) -> Response {
+    let Some(host) = headers
+        .get(HOST)
+        .and_then(|value| value.to_str().ok())
+        .map(without_port)
+        .filter(|host| is_valid_host(host))
+    else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+
+    let authority = if https_port == DEFAULT_HTTPS_PORT {
+        host.to_string()
+    } else {
+        format!("{host}:{https_port}")
+    };
+    let path = uri.path_and_query().map_or("/", |path| path.as_str());
+
+    Redirect::permanent(&format!("https://{authority}{path}")).into_response()
+}
+
+fn without_port(host: &str) -> &str {
+    match host.rsplit_once(':') {
+        // A colon inside brackets belongs to an IPv6 address, not to a port.
+        Some((name, port))
+            if port.chars().all(|char| char.is_ascii_digit())
+                && (!name.contains(':') || name.ends_with(']')) =>
+        {
+            name
+        }
+        _ => host,
+    }
+}
+
+/// Only host names, IPv4 addresses and bracketed IPv6 literals are echoed back, so the header
+/// cannot steer the redirect to another path, scheme or port.
+fn is_valid_host(host: &str) -> bool {
+    let ipv6 = host
+        .strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'));
+
+    if let Some(address) = ipv6 {
+        return !address.is_empty()
+            && address
+                .chars()
+                .all(|char| char.is_ascii_hexdigit() || matches!(char, ':' | '.'));
+    }
+
+    !host.is_empty()
+        && host
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || matches!(char, '.' | '-'))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        extract::State,
+        http::{HeaderMap, HeaderValue, StatusCode, Uri, header::HOST, header::LOCATION},
+    };
+
+    use crate::infrastructure::http::handlers::https_redirect::redirect_to_https;
+
+    async fn redirect(https_port: u16, host: Option<&'static str>) -> (StatusCode, Option<String>) {
+        let mut headers = HeaderMap::new();
+
+        if let Some(host) = host {
+            headers.insert(HOST, HeaderValue::from_static(host));
+        }
+
+        let response = redirect_to_https(
+            State(https_port),
+            headers,
+            Uri::from_static("/api/v1/auth/sign-up?next=%2F"),
+        )
+        .await;
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .map(|location| location.to_str().unwrap().to_string());
+
+        (response.status(), location)
+    }
+
+    #[tokio::test]
+    async fn should_redirect_to_the_same_host_and_path_on_the_https_port() {
+        for (https_port, host, location) in [
+            (
+                443,
+                "example.com",
+                "https://example.com/api/v1/auth/sign-up?next=%2F",
+            ),
+            (
+                443,
+                "example.com:80",
+                "https://example.com/api/v1/auth/sign-up?next=%2F",
+            ),
+            (
+                8443,
+                "example.com:8080",
+                "https://example.com:8443/api/v1/auth/sign-up?next=%2F",
+            ),
+            (
+                443,
+                "127.0.0.1:8080",
+                "https://127.0.0.1/api/v1/auth/sign-up?next=%2F",
+            ),
+            (443, "[::1]", "https://[::1]/api/v1/auth/sign-up?next=%2F"),
+            (
+                8443,
+                "[::1]:8080",
+                "https://[::1]:8443/api/v1/auth/sign-up?next=%2F",
+            ),
+            (
+                443,
+                "[2001:db8::1]:80",
+                "https://[2001:db8::1]/api/v1/auth/sign-up?next=%2F",
+            ),
+        ] {
+            assert_eq!(
+                redirect(https_port, Some(host)).await,
+                (StatusCode::PERMANENT_REDIRECT, Some(location.to_string())),
+                "{host}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn should_reject_missing_empty_or_hostile_hosts() {
+        assert_eq!(redirect(443, None).await, (StatusCode::BAD_REQUEST, None));
+
+        for host in [
+            "",
+            ":8080",
+            "[]",
+            "::1",
+            "[::1",
+            "[::1]x",
+            "[evil.com]",
+            "example.com:https",
+            "example.com:80:443",
+            "evil.com/phishing",
+            "evil.com?",
+            "evil.com#",
+            "user@evil.com",
+            "example.com\\evil.com",
+            "exa mple.com",
+        ] {
+            assert_eq!(
+                redirect(443, Some(host)).await,
+                (StatusCode::BAD_REQUEST, None),
+                "{host:?}"
+            );
+        }
+    }
+}
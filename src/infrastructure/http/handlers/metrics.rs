@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    http::header::CONTENT_TYPE,
+    response::{IntoResponse, Response},
+};
+
+use crate::infrastructure::adapters::prometheus_metrics::PrometheusMetricsAdapter;
+
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+pub async fn render_metrics(State(metrics): State<Arc<PrometheusMetricsAdapter>>) -> Response {
+    ([(CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)], metrics.render()).into_response()
+}
@@ -0,0 +1,101 @@
+use std::sync::LazyLock;
+
+use axum::{
+    http::header::CONTENT_TYPE,
+    response::{IntoResponse, Response},
+};
+
+use crate::infrastructure::http::openapi::spec_json;
+
+static SPEC: LazyLock<String> = LazyLock::new(spec_json);
+
+pub async fn render_openapi() -> Response {
+    ([(CONTENT_TYPE, "application/json")], SPEC.as_str()).into_response()
+}
+
+/// A Swagger UI page browsing `/openapi.json`.
+///
+/// Its assets are loaded from a CDN, so the binary does not embed them. They are pinned to one
+/// release and checked against their subresource integrity hashes, so the browser refuses a
+/// tampered CDN copy. Upgrading means recomputing the hashes with
+/// `openssl dgst -sha384 -binary <file> | openssl base64 -A` over the release's `dist` files.
+#[cfg(feature = "swagger-ui")]
+pub async fn render_swagger_ui() -> axum::response::Html<&'static str> {
+    axum::response::Html(
+        r##"<!doctype html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8">
+    <title>axum_tdd_api</title>
+    <link
+      rel="stylesheet"
+      href="https://unpkg.com/swagger-ui-dist@5.17.14/swagger-ui.css"
+      integrity="sha384-wxLW6kwyHktdDGr6Pv1zgm/VGJh99lfUbzSn6HNHBENZlCN7W602k9VkGdxuFvPn"
+      crossorigin="anonymous"
+    >
+  </head>
+  <body>
+    <div id="swagger-ui"></div>
+    <script
+      src="https://unpkg.com/swagger-ui-dist@5.17.14/swagger-ui-bundle.js"
+      integrity="sha384-wmyclcVGX/WhUkdkATwhaK1X1JtiNrr2EoYJ+diV3vj4v6OC5yCeSu+yW13SYJep"
+      crossorigin="anonymous"
+    ></script>
+    <script>
+      window.onload = () => {
+        window.ui = SwaggerUIBundle({ url: "/openapi.json", dom_id: "#swagger-ui" });
+      };
+    </script>
+  </body>
+</html>
+"##,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+
+    use crate::test_support::test_app::TestApp;
+
+    #[tokio::test]
+    async fn should_serve_the_openapi_spec() {
+        let spec = TestApp::new()
+            .client()
+            .get("/openapi.json")
+            .send()
+            .await
+            .assert_status(StatusCode::OK)
+            .json();
+
+        assert_eq!(spec["openapi"], "3.1.0");
+
+        for version in ["v1", "v2"] {
+            let sign_up = &spec["paths"][format!("/{version}/auth/sign-up")]["post"];
+
+            assert_eq!(
+                sign_up["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+                format!("#/components/schemas/{version}.SignUpRequest")
+            );
+        }
+
+        assert!(spec["components"]["schemas"]["UserView"].is_object());
+        assert!(spec["components"]["schemas"]["Problem"].is_object());
+    }
+
+    #[cfg(feature = "swagger-ui")]
+    #[tokio::test]
+    async fn should_load_pinned_swagger_ui_assets_under_integrity_checks() {
+        let page = TestApp::new()
+            .client()
+            .get("/docs")
+            .send()
+            .await
+            .assert_status(StatusCode::OK)
+            .body;
+
+        assert_eq!(page.matches("swagger-ui-dist@5.17.14/").count(), 2);
+        assert_eq!(page.matches("integrity=\"sha384-").count(), 2);
+        assert!(!page.contains("swagger-ui-dist@5/"));
+    }
+}
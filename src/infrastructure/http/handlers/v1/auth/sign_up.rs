@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use crate::{
+    application::{
+        inputs::{auth::sign_up::SignUpInput, request::RequestMetadata},
+        outputs::user::UserView,
+        ports::use_cases::auth::sign_up::SignUpPort,
+    },
+    infrastructure::http::{
+        extractors::{json::JsonBody, request_metadata::ClientMetadata},
+        handlers::auth::sign_up::SignUpProblems,
+        problem::Problem,
+    },
+};
+
+#[derive(Deserialize, ToSchema)]
+#[schema(as = v1::SignUpRequest)]
+pub struct SignUpRequest {
+    first_name: String,
+    last_name: String,
+    email: String,
+    password: String,
+    password_confirmation: String,
+}
+
+impl SignUpRequest {
+    fn into_input(self, metadata: RequestMetadata) -> SignUpInput {
+        SignUpInput {
+            first_name: self.first_name,
+            last_name: self.last_name,
+            email: self.email,
+            password: self.password,
+            password_confirmation: Some(self.password_confirmation),
+            metadata,
+        }
+    }
+}
+
+/// Registers a user, who can then sign in with the e-mail address and password given.
+#[utoipa::path(
+    post,
+    path = "/v1/auth/sign-up",
+    tag = "v1",
+    request_body = SignUpRequest,
+    responses(
+        (status = CREATED, description = "The user was registered", body = UserView),
+        SignUpProblems,
+    )
+)]
+pub async fn sign_up(
+    State(use_case): State<Arc<dyn SignUpPort>>,
+    ClientMetadata(metadata): ClientMetadata,
+    JsonBody(request): JsonBody<SignUpRequest>,
+) -> Response {
+    match use_case.perform(request.into_input(metadata)).await {
+        Ok(user) => (StatusCode::CREATED, Json(UserView::from(&user))).into_response(),
+        Err(err) => Problem::from(err).into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::http::StatusCode;
+    use serde_json::json;
+
+    use crate::{
+        application::ports::adapters::audit_log::AuditAction,
+        domain::{dtos::user::FindUserByEmailDto, repositories::user::UserPersistencePort},
+        test_support::test_app::{START_TIME, TestApp},
+    };
+
+    fn sign_up_body(email: &str) -> serde_json::Value {
+        json!({
+            "first_name": "John",
+            "last_name": "Doe",
+            "email": email,
+            "password": "SuperSecret123",
+            "password_confirmation": "SuperSecret123",
+        })
+    }
+
+    #[tokio::test]
+    async fn should_sign_up_each_email_once_within_the_client_quota() {
+        let app = TestApp::with_settings(&[("RATE_LIMIT_SIGN_UP_PER_HOUR", "2")]);
+        let client = app.client();
+
+        let user = client
+            .post("/v1/auth/sign-up")
+            .header("user-agent", "e2e")
+            .json(&sign_up_body("john.doe@mail.com"))
+            .send()
+            .await
+            .assert_status(StatusCode::CREATED)
+            .json();
+
+        assert_eq!(user["id"], "user_1");
+        assert_eq!(user["created_at"], "2023-11-14T22:13:20.000Z");
+        assert_eq!(user["created_at"], START_TIME.to_rfc3339());
+        assert_eq!(user["email_verified"], false);
+        assert_eq!(user.get("password"), None);
+        assert_eq!(user.get("password_hash"), None);
+
+        let duplicate = client
+            .post("/v1/auth/sign-up")
+            .json(&sign_up_body("john.doe@mail.com"))
+            .send()
+            .await
+            .assert_status(StatusCode::CONFLICT);
+
+        assert_eq!(
+            duplicate.header("content-type"),
+            Some("application/problem+json")
+        );
+        assert_eq!(duplicate.json()["request_id"], "request_2");
+
+        let refused = client
+            .post("/v1/auth/sign-up")
+            .json(&sign_up_body("jane.doe@mail.com"))
+            .send()
+            .await
+            .assert_status(StatusCode::TOO_MANY_REQUESTS);
+
+        assert!(refused.header("retry-after").is_some());
+
+        app.clock.advance(Duration::from_hours(1));
+
+        let user = client
+            .post("/v1/auth/sign-up")
+            .json(&sign_up_body("jane.doe@mail.com"))
+            .send()
+            .await
+            .assert_status(StatusCode::CREATED)
+            .json();
+
+        assert_eq!(user["id"], "user_2");
+        assert_eq!(user["created_at"], "2023-11-14T23:13:20.000Z");
+
+        let entries = app.audit_entries();
+
+        assert_eq!(
+            app.audited_actions(),
+            vec![AuditAction::SignUp, AuditAction::SignUp]
+        );
+        assert_eq!(entries[0].ip_address.as_deref(), Some("203.0.113.10"));
+        assert_eq!(entries[0].user_agent.as_deref(), Some("e2e"));
+        assert_eq!(
+            app.users
+                .find_by_email(FindUserByEmailDto {
+                    email: "jane.doe@mail.com".to_string(),
+                })
+                .unwrap()
+                .map(|user| user.password_hash().to_string()),
+            // Stored as given by the plain test hasher.
+            Some("SuperSecret123".to_string())
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    application::{
+        inputs::{auth::sign_up::SignUpInput, request::RequestMetadata},
+        ports::use_cases::auth::sign_up::SignUpPort,
+    },
+    domain::{entities::user::UserEntity, values::timestamp::Timestamp},
+    infrastructure::http::{
+        extractors::{json::JsonBody, request_metadata::ClientMetadata},
+        handlers::auth::sign_up::SignUpProblems,
+        problem::Problem,
+    },
+};
+
+#[derive(Deserialize, Serialize, ToSchema)]
+#[schema(as = v2::UserName)]
+pub struct UserName {
+    first: String,
+    last: String,
+}
+
+/// Version 2 drops the password confirmation, which is left to the client's form.
+#[derive(Deserialize, ToSchema)]
+#[schema(as = v2::SignUpRequest)]
+pub struct SignUpRequest {
+    name: UserName,
+    email: String,
+    password: String,
+}
+
+impl SignUpRequest {
+    fn into_input(self, metadata: RequestMetadata) -> SignUpInput {
+        SignUpInput {
+            first_name: self.name.first,
+            last_name: self.name.last,
+            email: self.email,
+            password: self.password,
+            password_confirmation: None,
+            metadata,
+        }
+    }
+}
+
+/// A registered user. `email_verified_at` is `null` until the e-mail address is verified.
+#[derive(Serialize, ToSchema)]
+#[schema(as = v2::User)]
+pub struct UserResponse {
+    id: String,
+    name: UserName,
+    email: String,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    email_verified_at: Option<Timestamp>,
+    #[schema(value_type = String, format = DateTime)]
+    created_at: Timestamp,
+    #[schema(value_type = String, format = DateTime)]
+    updated_at: Timestamp,
+}
+
+impl From<&UserEntity> for UserResponse {
+    fn from(user: &UserEntity) -> Self {
+        Self {
+            id: user.id().to_string(),
+            name: UserName {
+                first: user.first_name().to_string(),
+                last: user.last_name().to_string(),
+            },
+            email: user.email().to_string(),
+            email_verified_at: user.email_verified_at(),
+            created_at: user.created_at(),
+            updated_at: user.updated_at(),
+        }
+    }
+}
+
+/// Registers a user, who can then sign in with the e-mail address and password given.
+#[utoipa::path(
+    post,
+    path = "/v2/auth/sign-up",
+    tag = "v2",
+    request_body = SignUpRequest,
+    responses(
+        (status = CREATED, description = "The user was registered", body = UserResponse),
+        SignUpProblems,
+    )
+)]
+pub async fn sign_up(
+    State(use_case): State<Arc<dyn SignUpPort>>,
+    ClientMetadata(metadata): ClientMetadata,
+    JsonBody(request): JsonBody<SignUpRequest>,
+) -> Response {
+    match use_case.perform(request.into_input(metadata)).await {
+        Ok(user) => (StatusCode::CREATED, Json(UserResponse::from(&user))).into_response(),
+        Err(err) => Problem::from(err).into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+
+    use crate::test_support::test_app::TestApp;
+
+    #[tokio::test]
+    async fn should_sign_up_with_a_nested_name_and_no_confirmation() {
+        let app = TestApp::new();
+        let client = app.client();
+
+        let user = client
+            .post("/v2/auth/sign-up")
+            .json(&json!({
+                "name": { "first": "John", "last": "Doe" },
+                "email": "john.doe@mail.com",
+                "password": "SuperSecret123",
+            }))
+            .send()
+            .await
+            .assert_status(StatusCode::CREATED)
+            .json();
+
+        assert_eq!(
+            user,
+            json!({
+                "id": "user_1",
+                "name": { "first": "John", "last": "Doe" },
+                "email": "john.doe@mail.com",
+                "email_verified_at": null,
+                "created_at": "2023-11-14T22:13:20.000Z",
+                "updated_at": "2023-11-14T22:13:20.000Z",
+            })
+        );
+
+        // Both versions share the same users.
+        let duplicate = client
+            .post("/v1/auth/sign-up")
+            .json(&json!({
+                "first_name": "John",
+                "last_name": "Doe",
+                "email": "john.doe@mail.com",
+                "password": "SuperSecret123",
+                "password_confirmation": "SuperSecret123",
+            }))
+            .send()
+            .await
+            .assert_status(StatusCode::CONFLICT);
+
+        assert_eq!(duplicate.json()["status"], 409);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::domain::values::timestamp::Timestamp;
+
+const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The HTTP date of RFC 9110, such as `Tue, 14 Nov 2023 22:13:20 GMT`, which drops the
+/// milliseconds.
+#[must_use]
+pub fn http_date(timestamp: Timestamp) -> String {
+    let seconds = timestamp.unix_seconds();
+    let (year, month, day) = timestamp.date();
+    let seconds_of_day = seconds.rem_euclid(86_400);
+
+    format!(
+        "{}, {day:02} {} {year:04} {:02}:{:02}:{:02} GMT",
+        // The Unix epoch fell on a Thursday.
+        WEEKDAYS[index(seconds.div_euclid(86_400).rem_euclid(7))],
+        MONTHS[index(month - 1)],
+        seconds_of_day / 3600,
+        seconds_of_day / 60 % 60,
+        seconds_of_day % 60
+    )
+}
+
+/// An index into a table of weekdays or months, which the callers keep in range.
+fn index(value: i64) -> usize {
+    usize::try_from(value).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{domain::values::timestamp::Timestamp, infrastructure::http::http_date::http_date};
+
+    #[test]
+    fn should_format_as_http_date_without_milliseconds() {
+        assert_eq!(
+            http_date(Timestamp::from_unix_seconds(1_700_000_000)),
+            "Tue, 14 Nov 2023 22:13:20 GMT"
+        );
+        assert_eq!(
+            http_date(Timestamp::from_unix_millis(951_782_400_123)),
+            "Tue, 29 Feb 2000 00:00:00 GMT"
+        );
+        assert_eq!(
+            http_date(Timestamp::from_unix_millis(-1)),
+            "Wed, 31 Dec 1969 23:59:59 GMT"
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use listenfd::ListenFd;
+use tokio::net::TcpListener;
+#[cfg(unix)]
+use tokio::net::UnixListener;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListenAddress {
+    /// A `host:port` to bind.
+    Tcp(String),
+    /// A socket file to create.
+    Unix(String),
+    /// A socket passed by systemd socket activation, named after its `FileDescriptorName=`.
+    Systemd(String),
+}
+
+impl std::fmt::Display for ListenAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tcp(address) => write!(f, "tcp://{address}"),
+            Self::Unix(path) => write!(f, "unix://{path}"),
+            Self::Systemd(name) => write!(f, "systemd://{name}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ListenerError {
+    Bind { address: String, reason: String },
+    SystemdSocketMissing(String),
+}
+
+impl std::fmt::Display for ListenerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bind { address, reason } => write!(f, "Could not listen on {address}: {reason}"),
+            Self::SystemdSocketMissing(name) => {
+                write!(f, "systemd did not pass a socket named '{name}'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ListenerError {}
+
+pub enum BoundListener {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(UnixListener),
+}
+
+/// The sockets systemd passed to the process through socket activation, looked up by the
+/// `FileDescriptorName=` of their socket unit, or by position when they have no name.
+pub struct SystemdSockets {
+    fds: ListenFd,
+    names: Vec<String>,
+}
+
+impl SystemdSockets {
+    /// Takes the sockets passed to this process, if any.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self {
+            fds: ListenFd::from_env(),
+            names: std::env::var("LISTEN_FDNAMES")
+                .map(|names| parse_names(&names))
+                .unwrap_or_default(),
+        }
+    }
+
+    fn take(&mut self, name: &str) -> Result<BoundListener, ListenerError> {
+        let index = socket_index(&self.names, name, self.fds.len())
+            .ok_or_else(|| ListenerError::SystemdSocketMissing(name.to_string()))?;
+
+        let bind_error = |err: io::Error| ListenerError::Bind {
+            address: format!("systemd://{name}"),
+            reason: err.to_string(),
+        };
+
+        // A socket that is not TCP is left in place, so it can still be taken as a Unix one.
+        let listener = match self.fds.take_tcp_listener(index) {
+            Ok(Some(listener)) => {
+                listener.set_nonblocking(true).map_err(bind_error)?;
+
+                BoundListener::Tcp(TcpListener::from_std(listener).map_err(bind_error)?)
+            }
+            Ok(None) => return Err(ListenerError::SystemdSocketMissing(name.to_string())),
+            #[cfg(unix)]
+            Err(_) => {
+                let listener = self
+                    .fds
+                    .take_unix_listener(index)
+                    .map_err(bind_error)?
+                    .ok_or_else(|| ListenerError::SystemdSocketMissing(name.to_string()))?;
+
+                listener.set_nonblocking(true).map_err(bind_error)?;
+
+                BoundListener::Unix(UnixListener::from_std(listener).map_err(bind_error)?)
+            }
+            #[cfg(not(unix))]
+            Err(err) => return Err(bind_error(err)),
+        };
+
+        Ok(listener)
+    }
+}
+
+/// Splits `LISTEN_FDNAMES`, which lists the socket names in the order of their descriptors.
+fn parse_names(value: &str) -> Vec<String> {
+    value.split(':').map(str::to_string).collect()
+}
+
+/// The position of the socket called `name` among the `count` passed ones, falling back to
+/// reading `name` as a position.
+fn socket_index(names: &[String], name: &str, count: usize) -> Option<usize> {
+    names
+        .iter()
+        .position(|candidate| candidate == name)
+        .or_else(|| name.parse().ok())
+        .filter(|index| *index < count)
+}
+
+/// Opens `address`. Unix domain sockets get `unix_socket_mode` as permissions, replacing a
+/// socket file left behind by a previous run.
+///
+/// # Errors
+///
+/// Returns a [`ListenerError::Bind`] if the address cannot be bound, or a
+/// [`ListenerError::SystemdSocketMissing`] if systemd did not pass the named socket.
+pub async fn bind(
+    address: &ListenAddress,
+    unix_socket_mode: u32,
+    systemd: &mut SystemdSockets,
+) -> Result<BoundListener, ListenerError> {
+    let bind_error = |err: io::Error| ListenerError::Bind {
+        address: address.to_string(),
+        reason: err.to_string(),
+    };
+
+    match address {
+        ListenAddress::Tcp(host_and_port) => TcpListener::bind(host_and_port)
+            .await
+            .map(BoundListener::Tcp)
+            .map_err(bind_error),
+        ListenAddress::Unix(path) => {
+            bind_unix(Path::new(path), unix_socket_mode).map_err(bind_error)
+        }
+        ListenAddress::Systemd(name) => systemd.take(name),
+    }
+}
+
+/// Binds the socket inside a directory only the process can enter, and links it to `path` once
+/// it has its permissions, so it is never reachable with the default ones.
+#[cfg(unix)]
+fn bind_unix(path: &Path, mode: u32) -> io::Result<BoundListener> {
+    use std::{
+        fs::{DirBuilder, Permissions},
+        os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt},
+    };
+
+    // Only a stale socket is removed; any other file at that path is left for the operator.
+    if std::fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_socket()) {
+        std::fs::remove_file(path)?;
+    }
+
+    let staging = staging_dir(path)?;
+
+    // A directory left behind by a run that crashed while binding.
+    if std::fs::symlink_metadata(&staging).is_ok() {
+        std::fs::remove_dir_all(&staging)?;
+    }
+
+    DirBuilder::new().mode(0o700).create(&staging)?;
+
+    let staged = staging.join("s");
+    let listener = UnixListener::bind(&staged).and_then(|listener| {
+        std::fs::set_permissions(&staged, Permissions::from_mode(mode))?;
+        // Unlike a rename, a link never replaces a file that appeared at `path` in the meantime.
+        std::fs::hard_link(&staged, path)?;
+
+        Ok(listener)
+    });
+
+    let _ = std::fs::remove_dir_all(&staging);
+
+    Ok(BoundListener::Unix(listener?))
+}
+
+#[cfg(unix)]
+fn staging_dir(path: &Path) -> io::Result<PathBuf> {
+    let file_name = path.file_name().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "a Unix socket path needs a file name",
+        )
+    })?;
+    let mut staging = std::ffi::OsString::from(".");
+
+    staging.push(file_name);
+    staging.push(".bind");
+
+    Ok(path.with_file_name(staging))
+}
+
+#[cfg(not(unix))]
+fn bind_unix(_path: &Path, _mode: u32) -> io::Result<BoundListener> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "Unix domain sockets are not supported on this platform",
+    ))
+}
+
+/// The socket files created for `addresses`, to remove once the server has stopped.
+#[must_use]
+pub fn unix_socket_paths<'a>(
+    addresses: impl IntoIterator<Item = &'a ListenAddress>,
+) -> Vec<PathBuf> {
+    addresses
+        .into_iter()
+        .filter_map(|address| match address {
+            ListenAddress::Unix(path) => Some(PathBuf::from(path)),
+            ListenAddress::Tcp(_) | ListenAddress::Systemd(_) => None,
+        })
+        .collect()
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use axum::{Router, extract::ConnectInfo, routing::get};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::UnixStream,
+    };
+
+    use crate::infrastructure::http::{
+        listeners::{
+            BoundListener, ListenAddress, ListenerError, SystemdSockets, bind, parse_names,
+            socket_index,
+        },
+        peer::PeerAddr,
+    };
+
+    #[tokio::test]
+    async fn should_serve_over_unix_socket_with_configured_permissions() {
+        let path = std::env::temp_dir().join(format!("axum_tdd_api_{}.sock", std::process::id()));
+        let address = ListenAddress::Unix(path.to_str().unwrap().to_string());
+
+        // A socket left behind by a previous run is replaced.
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+
+        let Ok(BoundListener::Unix(listener)) =
+            bind(&address, 0o600, &mut SystemdSockets::from_env()).await
+        else {
+            panic!("expected a Unix listener");
+        };
+
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+            0o600
+        );
+        assert!(
+            !path
+                .with_file_name(format!(".{}.bind", path.file_name().unwrap().display()))
+                .exists()
+        );
+
+        let router = Router::new().route(
+            "/",
+            get(|ConnectInfo(peer): ConnectInfo<PeerAddr>| async move { format!("{peer:?}") }),
+        );
+
+        tokio::spawn(async move {
+            axum::serve(
+                listener,
+                router.into_make_service_with_connect_info::<PeerAddr>(),
+            )
+            .await
+        });
+
+        let mut stream = UnixStream::connect(&path).await.unwrap();
+
+        stream
+            .write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+
+        let mut response = String::new();
+
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with("Unix"));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn should_not_replace_a_file_that_is_not_a_socket() {
+        let path = std::env::temp_dir().join(format!("axum_tdd_api_{}.txt", std::process::id()));
+        let address = ListenAddress::Unix(path.to_str().unwrap().to_string());
+
+        std::fs::write(&path, "keep me").unwrap();
+
+        let result = bind(&address, 0o600, &mut SystemdSockets::from_env()).await;
+
+        assert!(matches!(result, Err(ListenerError::Bind { .. })));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "keep me");
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn should_find_systemd_sockets_by_name_or_position() {
+        let names = parse_names("public:internal");
+
+        assert_eq!(socket_index(&names, "public", 2), Some(0));
+        assert_eq!(socket_index(&names, "internal", 2), Some(1));
+        assert_eq!(socket_index(&names, "1", 2), Some(1));
+        assert_eq!(socket_index(&[], "0", 1), Some(0));
+    }
+
+    #[test]
+    fn should_not_find_systemd_sockets_that_were_not_passed() {
+        let names = parse_names("public:internal");
+
+        assert_eq!(socket_index(&names, "admin", 2), None);
+        assert_eq!(socket_index(&names, "internal", 1), None);
+        assert_eq!(socket_index(&names, "2", 2), None);
+        assert_eq!(socket_index(&names, "-1", 2), None);
+    }
+
+    #[tokio::test]
+    async fn should_fail_when_systemd_did_not_pass_the_socket() {
+        let mut systemd = SystemdSockets {
+            fds: listenfd::ListenFd::empty(),
+            names: parse_names("public"),
+        };
+
+        let result = bind(
+            &ListenAddress::Systemd("public".to_string()),
+            0o600,
+            &mut systemd,
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(ListenerError::SystemdSocketMissing(name)) if name == "public"
+        ));
+    }
+}
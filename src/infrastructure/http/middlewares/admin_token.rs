@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{StatusCode, header::AUTHORIZATION},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::domain::values::secret::Secret;
+
+/// Lets a request through only if it carries `Authorization: Bearer <token>`.
+pub async fn require_admin_token(
+    State(token): State<Arc<Secret<String>>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let provided_token = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match provided_token {
+        Some(provided_token)
+            if constant_time_eq(provided_token.as_bytes(), token.expose().as_bytes()) =>
+        {
+            next.run(request).await
+        }
+        _ => StatusCode::UNAUTHORIZED.into_response(),
+    }
+}
+
+/// Compares without short-circuiting, so response timing does not reveal how much of the token
+/// was guessed correctly.
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+
+    left.iter()
+        .zip(right)
+        .fold(0, |difference, (left, right)| difference | (left ^ right))
+        == 0
+}
@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderName, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    application::ports::adapters::{metrics::MetricsPort, time::TimePort},
+    domain::values::timestamp::Timestamp,
+    infrastructure::http::{
+        api_version::{ApiVersion, ApiVersionPolicy},
+        http_date::http_date,
+        problem::Problem,
+    },
+};
+
+static DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+static SUNSET: HeaderName = HeaderName::from_static("sunset");
+
+/// What every response of one API version is marked with, and when it stops being served.
+///
+/// The header values are rendered once, as the policy does not change while the server runs, but
+/// the sunset is checked against `time` on every request.
+pub struct ApiVersionState {
+    /// The `version` label requests are counted under.
+    label: &'static str,
+    deprecation: Option<HeaderValue>,
+    sunset: Option<HeaderValue>,
+    sunset_at: Option<Timestamp>,
+    time: Arc<dyn TimePort>,
+    metrics: Arc<dyn MetricsPort>,
+}
+
+impl ApiVersionState {
+    #[must_use]
+    pub fn new(
+        version: ApiVersion,
+        policy: ApiVersionPolicy,
+        time: Arc<dyn TimePort>,
+        metrics: Arc<dyn MetricsPort>,
+    ) -> Self {
+        Self::labelled(version.as_str(), policy, time, metrics)
+    }
+
+    /// Version 1 as served at the paths from before versioning, counted apart so the operator can
+    /// tell when clients stopped using them.
+    #[must_use]
+    pub fn unversioned(
+        policy: ApiVersionPolicy,
+        time: Arc<dyn TimePort>,
+        metrics: Arc<dyn MetricsPort>,
+    ) -> Self {
+        Self::labelled("unversioned", policy, time, metrics)
+    }
+
+    fn labelled(
+        label: &'static str,
+        policy: ApiVersionPolicy,
+        time: Arc<dyn TimePort>,
+        metrics: Arc<dyn MetricsPort>,
+    ) -> Self {
+        Self {
+            label,
+            // RFC 9745 dates are structured field dates: seconds since the epoch, after an `@`.
+            deprecation: policy.deprecated_at.and_then(|deprecated_at| {
+                HeaderValue::try_from(format!("@{}", deprecated_at.unix_seconds())).ok()
+            }),
+            // RFC 8594 dates are HTTP dates.
+            sunset: policy
+                .sunset_at
+                .and_then(|sunset_at| HeaderValue::try_from(http_date(sunset_at)).ok()),
+            sunset_at: policy.sunset_at,
+            time,
+            metrics,
+        }
+    }
+}
+
+/// Counts the request against its API version, and announces on the response when the version
+/// is deprecated and when it stops being served, if the policy says so.
+///
+/// Once the sunset has passed, requests are answered `410 Gone` without reaching the routes.
+pub async fn track_api_version(
+    State(state): State<Arc<ApiVersionState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    state.metrics.count_api_request(state.label);
+
+    let is_sunset = state
+        .sunset_at
+        .is_some_and(|sunset_at| state.time.now() >= sunset_at);
+
+    let mut response = if is_sunset {
+        Problem::new(
+            StatusCode::GONE,
+            "This version of the API is no longer served, see the Sunset header",
+        )
+        .into_response()
+    } else {
+        next.run(request).await
+    };
+
+    if let Some(deprecation) = &state.deprecation {
+        response
+            .headers_mut()
+            .insert(DEPRECATION.clone(), deprecation.clone());
+    }
+
+    if let Some(sunset) = &state.sunset {
+        response
+            .headers_mut()
+            .insert(SUNSET.clone(), sunset.clone());
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+
+    use crate::{domain::values::timestamp::Timestamp, test_support::test_app::TestApp};
+
+    #[tokio::test]
+    async fn should_announce_deprecation_and_count_requests_per_version() {
+        let app = TestApp::with_settings(&[
+            ("API_V1_DEPRECATED_AT", "2024-01-01"),
+            ("API_V1_SUNSET_AT", "2024-06-30T12:00:00Z"),
+        ]);
+        let client = app.client();
+
+        let v1 = client
+            .post("/v1/auth/sign-up")
+            .json(&json!({}))
+            .send()
+            .await
+            .assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+
+        assert_eq!(v1.header("deprecation"), Some("@1704067200"));
+        assert_eq!(v1.header("sunset"), Some("Sun, 30 Jun 2024 12:00:00 GMT"));
+
+        let v2 = client
+            .post("/v2/auth/sign-up")
+            .json(&json!({}))
+            .send()
+            .await
+            .assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+
+        assert_eq!(v2.header("deprecation"), None);
+        assert_eq!(v2.header("sunset"), None);
+
+        client.post("/v2/auth/sign-up").send().await;
+
+        let metrics = app.internal_client().get("/metrics").send().await.body;
+
+        assert!(metrics.contains("api_requests_total{version=\"v1\"} 1\n"));
+        assert!(metrics.contains("api_requests_total{version=\"v2\"} 2\n"));
+    }
+
+    #[tokio::test]
+    async fn should_serve_version_1_at_the_unversioned_paths_until_sunset() {
+        let app = TestApp::with_settings(&[
+            ("API_UNVERSIONED_DEPRECATED_AT", "2023-06-01"),
+            ("API_UNVERSIONED_SUNSET_AT", "2024-06-30"),
+        ]);
+        let client = app.client();
+
+        let response = client
+            .post("/auth/sign-up")
+            .json(&json!({}))
+            .send()
+            .await
+            .assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+
+        assert_eq!(response.header("deprecation"), Some("@1685577600"));
+        assert_eq!(
+            response.header("sunset"),
+            Some("Sun, 30 Jun 2024 00:00:00 GMT")
+        );
+
+        app.clock.set(Timestamp::from_unix_seconds(1_719_705_600));
+
+        let gone = client
+            .post("/auth/sign-up")
+            .json(&json!({}))
+            .send()
+            .await
+            .assert_status(StatusCode::GONE);
+
+        assert_eq!(
+            gone.header("content-type"),
+            Some("application/problem+json")
+        );
+        assert_eq!(gone.header("sunset"), Some("Sun, 30 Jun 2024 00:00:00 GMT"));
+
+        let metrics = app.internal_client().get("/metrics").send().await.body;
+
+        assert!(metrics.contains("api_requests_total{version=\"unversioned\"} 2\n"));
+    }
+
+    #[tokio::test]
+    async fn should_only_serve_the_unversioned_paths_once_their_deprecation_is_set() {
+        let _ = TestApp::new()
+            .client()
+            .post("/auth/sign-up")
+            .json(&json!({}))
+            .send()
+            .await
+            .assert_status(StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{
+        HeaderValue, Method, StatusCode,
+        header::{
+            ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+            ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_MAX_AGE, ACCESS_CONTROL_REQUEST_HEADERS,
+            ACCESS_CONTROL_REQUEST_METHOD, ORIGIN, VARY,
+        },
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+const ALLOWED_METHODS: &str = "GET, POST, PUT, PATCH, DELETE, OPTIONS";
+const PREFLIGHT_MAX_AGE_SECONDS: &str = "600";
+
+/// Which origins may call the API from a browser.
+#[derive(Clone)]
+pub struct CorsPolicy {
+    allows_origin: Arc<dyn Fn(&str) -> bool + Send + Sync>,
+}
+
+impl CorsPolicy {
+    /// Asks `allows_origin` on every request, so the allowed origins can follow configuration
+    /// reloads.
+    pub fn new(allows_origin: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            allows_origin: Arc::new(allows_origin),
+        }
+    }
+}
+
+/// Adds CORS headers for the origins currently allowed by the [`CorsPolicy`].
+///
+/// A reload takes effect on the next request. Requests from other origins get no CORS headers,
+/// which makes browsers block them. Every response varies on `Origin`, allowed or not, so a cache
+/// never hands the answer meant for one origin to another.
+pub async fn cors(State(policy): State<CorsPolicy>, request: Request, next: Next) -> Response {
+    let origin = request
+        .headers()
+        .get(ORIGIN)
+        .filter(|origin| {
+            origin
+                .to_str()
+                .is_ok_and(|origin| (policy.allows_origin)(origin))
+        })
+        .cloned();
+
+    let Some(origin) = origin else {
+        let mut response = next.run(request).await;
+
+        response
+            .headers_mut()
+            .append(VARY, HeaderValue::from_static("Origin"));
+
+        return response;
+    };
+
+    let is_preflight = request.method() == Method::OPTIONS
+        && request
+            .headers()
+            .contains_key(ACCESS_CONTROL_REQUEST_METHOD);
+
+    let mut response = if is_preflight {
+        let mut response = StatusCode::NO_CONTENT.into_response();
+        let headers = response.headers_mut();
+
+        headers.insert(
+            ACCESS_CONTROL_ALLOW_METHODS,
+            HeaderValue::from_static(ALLOWED_METHODS),
+        );
+        headers.insert(
+            ACCESS_CONTROL_MAX_AGE,
+            HeaderValue::from_static(PREFLIGHT_MAX_AGE_SECONDS),
+        );
+
+        if let Some(requested_headers) = request.headers().get(ACCESS_CONTROL_REQUEST_HEADERS) {
+            headers.insert(ACCESS_CONTROL_ALLOW_HEADERS, requested_headers.clone());
+        }
+
+        response
+    } else {
+        next.run(request).await
+    };
+
+    let headers = response.headers_mut();
+
+    headers.insert(ACCESS_CONTROL_ALLOW_ORIGIN, origin);
+    headers.append(VARY, HeaderValue::from_static("Origin"));
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+
+    use crate::test_support::test_app::TestApp;
+
+    #[tokio::test]
+    async fn should_vary_on_origin_whether_it_is_allowed_or_not() {
+        let app = TestApp::with_settings(&[("CORS_ALLOWED_ORIGINS", "https://app.example.com")]);
+        let client = app.client();
+
+        for (origin, allowed) in [
+            (
+                Some("https://app.example.com"),
+                Some("https://app.example.com"),
+            ),
+            (Some("https://evil.example.com"), None),
+            (None, None),
+        ] {
+            let mut request = client.get("/health/live");
+
+            if let Some(origin) = origin {
+                request = request.header("origin", origin);
+            }
+
+            let response = request.send().await.assert_status(StatusCode::OK);
+
+            assert_eq!(response.header("access-control-allow-origin"), allowed);
+            assert!(
+                response
+                    .headers
+                    .get_all("vary")
+                    .iter()
+                    .any(|vary| vary == "Origin"),
+                "{origin:?} got no Vary: Origin"
+            );
+        }
+    }
+}
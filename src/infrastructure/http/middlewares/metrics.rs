@@ -0,0 +1,37 @@
+use std::{sync::Arc, time::Instant};
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::application::ports::adapters::metrics::MetricsPort;
+
+/// Label used for requests that matched no route, so scanners cannot create a series per path.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Records the count and latency of every request, labeled by matched route and status.
+pub async fn track_http_metrics(
+    State(metrics): State<Arc<dyn MetricsPort>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let started_at = Instant::now();
+    let method = request.method().clone();
+    let route = request.extensions().get::<MatchedPath>().map_or_else(
+        || UNMATCHED_ROUTE.to_string(),
+        |path| path.as_str().to_string(),
+    );
+
+    let response = next.run(request).await;
+
+    metrics.observe_http_request(
+        method.as_str(),
+        &route,
+        response.status().as_u16(),
+        started_at.elapsed(),
+    );
+
+    response
+}
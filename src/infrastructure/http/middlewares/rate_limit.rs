@@ -0,0 +1,428 @@
+use std::{
+    convert::Infallible,
+    fmt::Write,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use axum::{
+    extract::{ConnectInfo, Request},
+    http::{HeaderMap, HeaderName, HeaderValue, StatusCode, header::AUTHORIZATION},
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+use tower::{Layer, Service};
+
+use crate::{
+    application::ports::adapters::{
+        metrics::MetricsPort,
+        rate_limit::{Quota, RateLimitDecision, RateLimitStorePort},
+        time::TimePort,
+    },
+    infrastructure::http::{peer::PeerAddr, problem::Problem},
+};
+
+const API_KEY_HEADER: &str = "x-api-key";
+const SIGN_UP_PERIOD_SECONDS: u32 = 3600;
+
+static RATE_LIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+static RATE_LIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+static RATE_LIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+static RATE_LIMIT_POLICY: HeaderName = HeaderName::from_static("ratelimit-policy");
+static RETRY_AFTER: HeaderName = HeaderName::from_static("retry-after");
+
+/// The identity of the caller, inserted by authentication middleware for the routes it guards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticatedUser(pub String);
+
+/// What a quota is counted per. Requests lacking the identity a key asks for are counted per
+/// client IP instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitKey {
+    ClientIp,
+    User,
+    ApiKey,
+}
+
+/// A named quota applied to the routes it is layered on.
+pub struct RateLimitPolicy {
+    name: &'static str,
+    key: RateLimitKey,
+    period_seconds: u32,
+    limit: Box<dyn Fn() -> u32 + Send + Sync>,
+}
+
+impl RateLimitPolicy {
+    /// Reads the limit through `limit` on every request, so it can follow configuration reloads.
+    /// A limit of `0` lets every request through.
+    pub fn new(
+        name: &'static str,
+        key: RateLimitKey,
+        period_seconds: u32,
+        limit: impl Fn() -> u32 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name,
+            key,
+            period_seconds,
+            limit: Box::new(limit),
+        }
+    }
+
+    /// Sign-ups per client IP and hour, read through `limit` like any other policy.
+    pub fn sign_up(limit: impl Fn() -> u32 + Send + Sync + 'static) -> Self {
+        Self::new(
+            "sign_up",
+            RateLimitKey::ClientIp,
+            SIGN_UP_PERIOD_SECONDS,
+            limit,
+        )
+    }
+
+    fn store_key(&self, request: &Request) -> String {
+        let identity = match self.key {
+            RateLimitKey::ClientIp => None,
+            RateLimitKey::User => request
+                .extensions()
+                .get::<AuthenticatedUser>()
+                .map(|user| format!("user:{}", user.0)),
+            // Only a digest of the key is stored, so the store never holds a usable credential.
+            RateLimitKey::ApiKey => api_key(request.headers()).map(|api_key| {
+                Sha256::digest(api_key.as_bytes()).iter().fold(
+                    "api_key:".to_string(),
+                    |mut key, byte| {
+                        let _ = write!(key, "{byte:02x}");
+                        key
+                    },
+                )
+            }),
+        };
+
+        let identity = identity.unwrap_or_else(|| {
+            // Everything arriving over a Unix domain socket shares a single local quota.
+            match request.extensions().get::<ConnectInfo<PeerAddr>>() {
+                Some(ConnectInfo(PeerAddr::Tcp(address))) => format!("ip:{}", address.ip()),
+                Some(ConnectInfo(PeerAddr::Unix)) => "unix".to_string(),
+                None => "ip:unknown".to_string(),
+            }
+        });
+
+        format!("{}:{identity}", self.name)
+    }
+}
+
+/// Enforces a [`RateLimitPolicy`] against a pluggable store, timed by a [`TimePort`].
+pub struct RateLimiter {
+    policy: RateLimitPolicy,
+    store: Arc<dyn RateLimitStorePort>,
+    time: Arc<dyn TimePort>,
+    metrics: Arc<dyn MetricsPort>,
+}
+
+impl RateLimiter {
+    #[must_use]
+    pub fn new(
+        policy: RateLimitPolicy,
+        store: Arc<dyn RateLimitStorePort>,
+        time: Arc<dyn TimePort>,
+        metrics: Arc<dyn MetricsPort>,
+    ) -> Self {
+        Self {
+            policy,
+            store,
+            time,
+            metrics,
+        }
+    }
+
+    /// The store key and quota `request` counts against, or `None` when the limit is disabled.
+    fn quota_for(&self, request: &Request) -> Option<(String, Quota)> {
+        let limit = (self.policy.limit)();
+
+        if limit == 0 {
+            return None;
+        }
+
+        let quota = Quota {
+            limit,
+            period_seconds: self.policy.period_seconds,
+        };
+
+        Some((self.policy.store_key(request), quota))
+    }
+
+    /// Counts one request by `key` against `quota`. Returns `None` when the store failed, so the
+    /// request goes uncounted.
+    ///
+    /// Stores may block on I/O, so they are called off the async workers.
+    async fn acquire(&self, key: String, quota: Quota) -> Option<RateLimitDecision> {
+        let store = self.store.clone();
+        let now_ms = self.time.now().unix_millis();
+        let acquired =
+            tokio::task::spawn_blocking(move || store.acquire(&key, quota, now_ms)).await;
+
+        let err = match acquired {
+            Ok(Ok(decision)) => return Some(decision),
+            Ok(Err(err)) => err.to_string(),
+            Err(err) => err.to_string(),
+        };
+
+        tracing::error!(
+            error = %err,
+            policy = self.policy.name,
+            "Could not check rate limit, letting the request through"
+        );
+        self.metrics
+            .count_rate_limit_store_failure(self.policy.name);
+
+        None
+    }
+}
+
+/// Layers a [`RateLimiter`] onto the routes it wraps.
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    limiter: Arc<RateLimiter>,
+}
+
+impl RateLimitLayer {
+    #[must_use]
+    pub const fn new(limiter: Arc<RateLimiter>) -> Self {
+        Self { limiter }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimit {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+/// Counts each request against the limiter's policy and answers `429 Too Many Requests` once the
+/// quota is used up.
+///
+/// Every counted response carries the `RateLimit-*` headers, and refused ones `Retry-After`. A
+/// failing store lets requests through rather than taking the routes down with it; each failure
+/// is logged and counted in the metrics.
+#[derive(Clone)]
+pub struct RateLimit<S> {
+    inner: S,
+    limiter: Arc<RateLimiter>,
+}
+
+impl<S> Service<Request> for RateLimit<S>
+where
+    S: Service<Request, Response = Response, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        // The service that was polled ready handles the request; its clone waits for the next one.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let Some((key, quota)) = self.limiter.quota_for(&request) else {
+            return Box::pin(inner.call(request));
+        };
+        let limiter = self.limiter.clone();
+
+        Box::pin(async move {
+            let Some(decision) = limiter.acquire(key, quota).await else {
+                return inner.call(request).await;
+            };
+
+            let mut response = if decision.allowed {
+                inner.call(request).await?
+            } else {
+                Problem::new(
+                    StatusCode::TOO_MANY_REQUESTS,
+                    "Too many requests, retry after the delay in the Retry-After header",
+                )
+                .into_response()
+            };
+
+            insert_headers(response.headers_mut(), &decision, quota);
+
+            Ok(response)
+        })
+    }
+}
+
+fn insert_headers(headers: &mut HeaderMap, decision: &RateLimitDecision, quota: Quota) {
+    headers.insert(RATE_LIMIT_LIMIT.clone(), decision.limit.into());
+    headers.insert(RATE_LIMIT_REMAINING.clone(), decision.remaining.into());
+    headers.insert(RATE_LIMIT_RESET.clone(), decision.reset_seconds.into());
+
+    if let Ok(policy) =
+        HeaderValue::from_str(&format!("{};w={}", quota.limit, quota.period_seconds))
+    {
+        headers.insert(RATE_LIMIT_POLICY.clone(), policy);
+    }
+
+    if let Some(retry_after_seconds) = decision.retry_after_seconds {
+        headers.insert(RETRY_AFTER.clone(), retry_after_seconds.into());
+    }
+}
+
+fn api_key(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(API_KEY_HEADER)
+        .or_else(|| headers.get(AUTHORIZATION))
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.strip_prefix("Bearer ").unwrap_or(value))
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+    use axum::{Router, body::Body, http::Request, routing::post};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+    use tower::ServiceExt;
+
+    use crate::{
+        application::ports::adapters::rate_limit::{
+            Quota, RateLimitDecision, RateLimitError, RateLimitStorePort,
+        },
+        domain::values::timestamp::Timestamp,
+        infrastructure::{
+            adapters::{
+                prometheus_metrics::PrometheusMetricsAdapter,
+                rate_limit::in_memory::InMemoryRateLimitStore,
+            },
+            http::{
+                middlewares::rate_limit::{
+                    RateLimitKey, RateLimitLayer, RateLimitPolicy, RateLimiter,
+                },
+                peer::PeerAddr,
+            },
+        },
+        test_support::fakes::ManualClock,
+    };
+
+    struct FailingStore;
+
+    impl RateLimitStorePort for FailingStore {
+        fn acquire(
+            &self,
+            _key: &str,
+            _quota: Quota,
+            _now_ms: i64,
+        ) -> Result<RateLimitDecision, RateLimitError> {
+            Err(RateLimitError::Storage("database is locked".to_string()))
+        }
+    }
+
+    async fn sign_up(address: SocketAddr) -> String {
+        let mut stream = TcpStream::connect(address).await.unwrap();
+
+        stream
+            .write_all(
+                b"POST /sign-up HTTP/1.1\r\nHost: localhost\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            )
+            .await
+            .unwrap();
+
+        let mut response = String::new();
+
+        stream.read_to_string(&mut response).await.unwrap();
+
+        response
+    }
+
+    #[tokio::test]
+    async fn should_refuse_requests_over_quota_until_clock_moves_on() {
+        let clock = Arc::new(ManualClock::new(Timestamp::from_unix_seconds(
+            1_700_000_000,
+        )));
+        let limiter = RateLimiter::new(
+            RateLimitPolicy::new("sign_up", RateLimitKey::ClientIp, 3600, || 2),
+            Arc::new(InMemoryRateLimitStore::new()),
+            clock.clone(),
+            Arc::new(PrometheusMetricsAdapter::new()),
+        );
+        let router = Router::new()
+            .route("/sign-up", post(|| async { "signed up" }))
+            .route_layer(RateLimitLayer::new(Arc::new(limiter)));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            axum::serve(
+                listener,
+                router.into_make_service_with_connect_info::<PeerAddr>(),
+            )
+            .await
+        });
+
+        let first = sign_up(address).await;
+
+        assert!(first.starts_with("HTTP/1.1 200 OK"));
+        assert!(first.contains("ratelimit-limit: 2\r\n"));
+        assert!(first.contains("ratelimit-remaining: 1\r\n"));
+        assert!(first.contains("ratelimit-policy: 2;w=3600\r\n"));
+        assert!(
+            sign_up(address)
+                .await
+                .contains("ratelimit-remaining: 0\r\n")
+        );
+
+        let refused = sign_up(address).await;
+
+        assert!(refused.starts_with("HTTP/1.1 429 Too Many Requests"));
+        assert!(refused.contains("content-type: application/problem+json\r\n"));
+        assert!(refused.contains("retry-after: 1800\r\n"));
+        assert!(refused.contains("ratelimit-reset: 3600\r\n"));
+
+        clock.advance(Duration::from_mins(30));
+
+        assert!(sign_up(address).await.starts_with("HTTP/1.1 200 OK"));
+    }
+
+    #[tokio::test]
+    async fn should_let_requests_through_and_count_store_failures() {
+        let metrics = Arc::new(PrometheusMetricsAdapter::new());
+        let limiter = RateLimiter::new(
+            RateLimitPolicy::new("sign_up", RateLimitKey::ClientIp, 3600, || 2),
+            Arc::new(FailingStore),
+            Arc::new(ManualClock::new(Timestamp::from_unix_seconds(
+                1_700_000_000,
+            ))),
+            metrics.clone(),
+        );
+        let router = Router::new()
+            .route("/sign-up", post(|| async { "signed up" }))
+            .route_layer(RateLimitLayer::new(Arc::new(limiter)));
+
+        let response = router
+            .oneshot(Request::post("/sign-up").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert!(!response.headers().contains_key("ratelimit-limit"));
+        assert!(
+            metrics
+                .render()
+                .contains("rate_limit_store_failures_total{policy=\"sign_up\"} 1\n")
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::HeaderValue,
+    middleware::Next,
+    response::Response,
+};
+
+use crate::application::ports::adapters::id_generator::IdGeneratorPort;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static CURRENT_REQUEST_ID: String;
+}
+
+/// The id of the request being handled, available to handlers as an extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+/// Gives every request an id, keeping the one the caller sent in `X-Request-Id` when it is
+/// usable and generating one otherwise.
+///
+/// The id is stored in the request extensions, echoed in the response header and reported in
+/// error bodies, so a user can quote it and operators can find the matching log lines.
+pub async fn assign_request_id(
+    State(id_generator): State<Arc<dyn IdGeneratorPort>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_valid_request_id(value))
+        .map_or_else(|| id_generator.generate_id(), str::to_string);
+
+    request
+        .extensions_mut()
+        .insert(RequestId(request_id.clone()));
+
+    let mut response = CURRENT_REQUEST_ID
+        .scope(request_id.clone(), next.run(request))
+        .await;
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+
+    response
+}
+
+/// The id of the request being handled, if called while handling one.
+#[must_use]
+pub fn current_request_id() -> Option<String> {
+    CURRENT_REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Caller-supplied ids end up in logs and response headers, so only short printable ones are
+/// kept.
+fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LENGTH
+        && value.chars().all(|char| char.is_ascii_graphic())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, middleware, routing::get};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    use crate::{
+        application::ports::adapters::id_generator::IdGeneratorPort,
+        domain::errors::domain::DomainError,
+        infrastructure::http::{middlewares::request_id::assign_request_id, problem::Problem},
+        test_support::fakes::SequentialIdGenerator,
+    };
+
+    async fn send(address: std::net::SocketAddr, headers: &str) -> String {
+        let mut stream = TcpStream::connect(address).await.unwrap();
+
+        stream
+            .write_all(
+                format!("GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n{headers}\r\n")
+                    .as_bytes(),
+            )
+            .await
+            .unwrap();
+
+        let mut response = String::new();
+
+        stream.read_to_string(&mut response).await.unwrap();
+
+        response
+    }
+
+    #[tokio::test]
+    async fn should_keep_incoming_id_or_generate_one_and_report_it_in_errors() {
+        let id_generator: Arc<dyn IdGeneratorPort> =
+            Arc::new(SequentialIdGenerator::new("request"));
+        let router = Router::new()
+            .route(
+                "/",
+                get(|| async { Problem::from(DomainError::UserAlreadyExists) }),
+            )
+            .layer(middleware::from_fn_with_state(
+                id_generator,
+                assign_request_id,
+            ));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+        let propagated = send(address, "X-Request-Id: support-ticket-42\r\n").await;
+
+        assert!(propagated.contains("x-request-id: support-ticket-42\r\n"));
+        assert!(propagated.contains(r#""request_id":"support-ticket-42""#));
+
+        for (headers, generated_id) in [
+            ("", "request_1"),
+            ("X-Request-Id: \r\n", "request_2"),
+            ("X-Request-Id: two words\r\n", "request_3"),
+        ] {
+            let generated = send(address, headers).await;
+
+            assert!(generated.contains(&format!("x-request-id: {generated_id}\r\n")));
+            assert!(generated.contains(&format!(r#""request_id":"{generated_id}""#)));
+        }
+    }
+}
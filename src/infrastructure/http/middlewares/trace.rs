@@ -0,0 +1,62 @@
+use std::time::Instant;
+
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use tracing::{Instrument, field::Empty};
+
+use crate::infrastructure::http::{
+    middlewares::request_id::RequestId,
+    trace_context::{join_trace, with_trace_id},
+};
+
+/// Runs every request inside an `http_request` span and logs its outcome once the response is
+/// ready. Handlers and use cases log within that span, so their lines carry the request and trace
+/// ids.
+///
+/// The span joins the W3C trace the caller propagated in `traceparent`, if any, and its trace id
+/// is what error bodies report.
+pub async fn trace_requests(request: Request, next: Next) -> Response {
+    let started_at = Instant::now();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string());
+    let request_id = request
+        .extensions()
+        .get::<RequestId>()
+        .map(|request_id| request_id.0.clone());
+
+    let span = tracing::info_span!(
+        "http_request",
+        method = %request.method(),
+        route = route.as_deref().unwrap_or("unmatched"),
+        request_id = request_id.as_deref(),
+        trace_id = Empty,
+        status = Empty,
+        latency_ms = Empty,
+    );
+
+    let trace_id = join_trace(&span, request.headers());
+
+    span.record("trace_id", trace_id.as_str());
+
+    let response = with_trace_id(trace_id, next.run(request).instrument(span.clone())).await;
+    let status = response.status();
+    let latency_ms = u64::try_from(started_at.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+    span.record("status", status.as_u16());
+    span.record("latency_ms", latency_ms);
+
+    span.in_scope(|| {
+        if status.is_server_error() {
+            tracing::error!("request failed");
+        } else {
+            tracing::info!("request completed");
+        }
+    });
+
+    response
+}
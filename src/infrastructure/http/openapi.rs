@@ -0,0 +1,57 @@
+use utoipa::{Modify, OpenApi, openapi::OpenApi as Spec};
+
+use crate::infrastructure::http::{
+    handlers::{v1, v2},
+    problem::Problem,
+};
+
+/// The `OpenAPI` 3.1 contract of the public API, generated from the handlers and the types they
+/// exchange. Its rendering is committed as `openapi.json`.
+#[derive(OpenApi)]
+#[openapi(
+    info(description = "Users signing up to the service."),
+    paths(v1::auth::sign_up::sign_up, v2::auth::sign_up::sign_up),
+    // Referenced by the shared problem responses, which do not register the schemas they use.
+    components(schemas(Problem)),
+    modifiers(&Unlicensed),
+    tags(
+        (name = "v1", description = "Version 1 of the API"),
+        (name = "v2", description = "Version 2 of the API, which nests user names"),
+    ),
+)]
+pub struct ApiDoc;
+
+/// The crate declares no license, which utoipa would otherwise render as an empty one.
+struct Unlicensed;
+
+impl Modify for Unlicensed {
+    fn modify(&self, spec: &mut Spec) {
+        spec.info.license = None;
+    }
+}
+
+/// The spec as pretty-printed JSON, as `axum_tdd_api openapi` prints it.
+///
+/// # Panics
+///
+/// Never in practice: the generated spec only holds JSON-serializable values.
+#[must_use]
+pub fn spec_json() -> String {
+    ApiDoc::openapi()
+        .to_pretty_json()
+        .expect("OpenAPI spec is not serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::http::openapi::spec_json;
+
+    #[test]
+    fn should_match_the_committed_spec() {
+        // Compared as a whole rather than with assert_eq, which would print both specs.
+        assert!(
+            include_str!("../../../openapi.json") == format!("{}\n", spec_json()),
+            "openapi.json is out of date, regenerate it with `cargo run -- openapi > openapi.json`"
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use axum::{
+    Json,
+    http::{HeaderValue, StatusCode, header::CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::{
+    domain::errors::domain::DomainError,
+    infrastructure::http::{
+        middlewares::request_id::current_request_id, trace_context::current_trace_id,
+    },
+};
+
+const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+
+/// An RFC 9457 problem details body.
+///
+/// The request and trace ids of the failing request are attached, so an error reported by a
+/// client can be matched with the log lines and spans it produced.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct Problem {
+    #[serde(rename = "type")]
+    pub problem_type: &'static str,
+    pub title: &'static str,
+    pub status: u16,
+    pub detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
+}
+
+impl Problem {
+    #[must_use]
+    pub fn new(status: StatusCode, detail: impl Into<String>) -> Self {
+        Self {
+            problem_type: "about:blank",
+            title: status.canonical_reason().unwrap_or("Error"),
+            status: status.as_u16(),
+            detail: detail.into(),
+            request_id: current_request_id(),
+            trace_id: current_trace_id(),
+        }
+    }
+}
+
+impl From<DomainError> for Problem {
+    fn from(err: DomainError) -> Self {
+        match err {
+            DomainError::PasswordMismatch => {
+                Self::new(StatusCode::UNPROCESSABLE_ENTITY, err.to_string())
+            }
+            DomainError::UserAlreadyExists | DomainError::Conflict(_) => {
+                Self::new(StatusCode::CONFLICT, err.to_string())
+            }
+            // The cause ends up in the logs; clients only get the ids to report.
+            DomainError::Internal(cause) => {
+                tracing::error!(error = %cause, "Request failed with an internal error");
+
+                Self::new(StatusCode::INTERNAL_SERVER_ERROR, "Something went wrong")
+            }
+        }
+    }
+}
+
+impl IntoResponse for Problem {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let mut response = (status, Json(self)).into_response();
+
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(PROBLEM_CONTENT_TYPE));
+
+        response
+    }
+}
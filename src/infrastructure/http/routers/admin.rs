@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use axum::{Router, middleware, routing::get};
+
+use crate::{
+    application::ports::adapters::audit_log::AuditLogPort,
+    domain::values::secret::Secret,
+    infrastructure::http::{
+        handlers::admin::audit_log::list_audit_entries,
+        middlewares::admin_token::require_admin_token,
+    },
+};
+
+/// Routes reserved to operators holding the admin API token.
+pub fn admin_router(audit_log: Arc<dyn AuditLogPort>, admin_token: Secret<String>) -> Router {
+    Router::new()
+        .route("/admin/audit-log", get(list_audit_entries))
+        .with_state(audit_log)
+        .layer(middleware::from_fn_with_state(
+            Arc::new(admin_token),
+            require_admin_token,
+        ))
+}
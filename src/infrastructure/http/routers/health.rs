@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use axum::{Router, routing::get};
+
+use crate::infrastructure::http::handlers::health::{
+    HealthDetail, HealthProbes, HealthState, live, ready, startup,
+};
+
+/// Probes for the orchestrator. They are left unauthenticated on purpose, so the public listener
+/// should only be given `HealthDetail::StatusOnly`.
+pub fn health_router(state: Arc<HealthState>, detail: HealthDetail) -> Router {
+    Router::new()
+        .route("/health/live", get(live))
+        .route("/health/ready", get(ready))
+        .route("/health/startup", get(startup))
+        .with_state(HealthProbes { state, detail })
+}
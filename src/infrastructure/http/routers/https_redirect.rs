@@ -0,0 +1,10 @@
+use axum::Router;
+
+use crate::infrastructure::http::handlers::https_redirect::redirect_to_https;
+
+/// Answers every request with a permanent redirect to HTTPS on `https_port`.
+pub fn https_redirect_router(https_port: u16) -> Router {
+    Router::new()
+        .fallback(redirect_to_https)
+        .with_state(https_port)
+}
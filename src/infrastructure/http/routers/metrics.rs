@@ -0,0 +1,14 @@
+use std::sync::Arc;
+
+use axum::{Router, routing::get};
+
+use crate::infrastructure::{
+    adapters::prometheus_metrics::PrometheusMetricsAdapter, http::handlers::metrics::render_metrics,
+};
+
+/// The Prometheus scrape endpoint.
+pub fn metrics_router(metrics: Arc<PrometheusMetricsAdapter>) -> Router {
+    Router::new()
+        .route("/metrics", get(render_metrics))
+        .with_state(metrics)
+}
@@ -0,0 +1,17 @@
+use axum::{Router, routing::get};
+
+use crate::infrastructure::http::handlers::openapi::render_openapi;
+
+/// The API spec, along with a Swagger UI page at `/docs` when built with the `swagger-ui`
+/// feature.
+pub fn openapi_router() -> Router {
+    let router = Router::new().route("/openapi.json", get(render_openapi));
+
+    #[cfg(feature = "swagger-ui")]
+    let router = router.route(
+        "/docs",
+        get(crate::infrastructure::http::handlers::openapi::render_swagger_ui),
+    );
+
+    router
+}
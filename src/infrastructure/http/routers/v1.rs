@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use axum::{Router, routing::post};
+
+use crate::{
+    application::ports::use_cases::auth::sign_up::SignUpPort,
+    infrastructure::http::{
+        handlers::v1::auth::sign_up::sign_up,
+        middlewares::rate_limit::{RateLimitLayer, RateLimiter},
+    },
+};
+
+/// Version 1 of the API. Sign-ups are rate limited per client IP, to slow down mass
+/// registrations.
+pub fn v1_router(
+    sign_up_use_case: Arc<dyn SignUpPort>,
+    sign_up_limiter: Arc<RateLimiter>,
+) -> Router {
+    Router::new()
+        .route("/auth/sign-up", post(sign_up))
+        .with_state(sign_up_use_case)
+        .route_layer(RateLimitLayer::new(sign_up_limiter))
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use axum::{Router, routing::post};
+
+use crate::{
+    application::ports::use_cases::auth::sign_up::SignUpPort,
+    infrastructure::http::{
+        handlers::v2::auth::sign_up::sign_up,
+        middlewares::rate_limit::{RateLimitLayer, RateLimiter},
+    },
+};
+
+/// Version 2 of the API. Its sign-ups count against the same limiter as version 1, so switching
+/// versions does not reset a client's quota.
+pub fn v2_router(
+    sign_up_use_case: Arc<dyn SignUpPort>,
+    sign_up_limiter: Arc<RateLimiter>,
+) -> Router {
+    Router::new()
+        .route("/auth/sign-up", post(sign_up))
+        .with_state(sign_up_use_case)
+        .route_layer(RateLimitLayer::new(sign_up_limiter))
+}
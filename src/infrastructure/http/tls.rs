@@ -0,0 +1,488 @@
+use std::{
+    io,
+    net::SocketAddr,
+    path::Path,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use axum::serve::Listener;
+use rustls::{
+    RootCertStore, ServerConfig,
+    crypto::{CryptoProvider, ring},
+    pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject},
+    server::WebPkiClientVerifier,
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::{mpsc, watch},
+    task::JoinHandle,
+};
+use tokio_rustls::{TlsAcceptor, server::TlsStream};
+
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+const PENDING_CONNECTIONS: usize = 128;
+
+#[derive(Debug)]
+pub enum TlsError {
+    Certificates { path: String, reason: String },
+    PrivateKey { path: String, reason: String },
+    ClientCa { path: String, reason: String },
+    Config(String),
+}
+
+impl std::fmt::Display for TlsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Certificates { path, reason } => {
+                write!(f, "Could not read TLS certificates from '{path}': {reason}")
+            }
+            Self::PrivateKey { path, reason } => {
+                write!(f, "Could not read TLS private key from '{path}': {reason}")
+            }
+            Self::ClientCa { path, reason } => {
+                write!(
+                    f,
+                    "Could not read client CA certificates from '{path}': {reason}"
+                )
+            }
+            Self::Config(reason) => write!(f, "Invalid TLS configuration: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for TlsError {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClientAuth {
+    #[default]
+    Required,
+    Optional,
+}
+
+impl FromStr for ClientAuth {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "required" => Ok(Self::Required),
+            "optional" => Ok(Self::Optional),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Certificates are re-read whenever their files change, so renewing them needs no restart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsConfig {
+    pub cert_path: String,
+    pub key_path: String,
+    pub client_ca_path: Option<String>,
+    pub client_auth: ClientAuth,
+    pub redirect_port: Option<u16>,
+}
+
+/// Reads the certificate, key and optional client CA named in `config` into a rustls
+/// configuration serving HTTP/1.1.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - A file cannot be read or holds no usable PEM item (`TlsError::Certificates`,
+///   `TlsError::PrivateKey`, `TlsError::ClientCa`)
+/// - The key does not match the certificate (`TlsError::Config`)
+pub fn load_server_config(config: &TlsConfig) -> Result<Arc<ServerConfig>, TlsError> {
+    let provider = Arc::new(ring::default_provider());
+
+    let certificates =
+        read_certificates(&config.cert_path).map_err(|reason| TlsError::Certificates {
+            path: config.cert_path.clone(),
+            reason,
+        })?;
+
+    let key =
+        PrivateKeyDer::from_pem_file(&config.key_path).map_err(|err| TlsError::PrivateKey {
+            path: config.key_path.clone(),
+            reason: err.to_string(),
+        })?;
+
+    let builder = ServerConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|err| TlsError::Config(err.to_string()))?;
+
+    let builder = match &config.client_ca_path {
+        Some(path) => {
+            builder.with_client_cert_verifier(client_verifier(path, config.client_auth, provider)?)
+        }
+        None => builder.with_no_client_auth(),
+    };
+
+    let mut server_config = builder
+        .with_single_cert(certificates, key)
+        .map_err(|err| TlsError::Config(err.to_string()))?;
+
+    server_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+    Ok(Arc::new(server_config))
+}
+
+fn read_certificates(path: &str) -> Result<Vec<CertificateDer<'static>>, String> {
+    let certificates = CertificateDer::pem_file_iter(path)
+        .and_then(Iterator::collect::<Result<Vec<_>, _>>)
+        .map_err(|err| err.to_string())?;
+
+    if certificates.is_empty() {
+        return Err("no certificate found".to_string());
+    }
+
+    Ok(certificates)
+}
+
+fn client_verifier(
+    path: &str,
+    client_auth: ClientAuth,
+    provider: Arc<CryptoProvider>,
+) -> Result<Arc<dyn rustls::server::danger::ClientCertVerifier>, TlsError> {
+    let client_ca_error = |reason: String| TlsError::ClientCa {
+        path: path.to_string(),
+        reason,
+    };
+
+    let mut roots = RootCertStore::empty();
+
+    for certificate in read_certificates(path).map_err(client_ca_error)? {
+        roots
+            .add(certificate)
+            .map_err(|err| client_ca_error(err.to_string()))?;
+    }
+
+    let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider);
+    let verifier = match client_auth {
+        ClientAuth::Required => verifier,
+        ClientAuth::Optional => verifier.allow_unauthenticated(),
+    };
+
+    verifier
+        .build()
+        .map_err(|err| client_ca_error(err.to_string()))
+}
+
+/// Reloads the TLS configuration whenever one of its files changes, checking every
+/// `poll_interval`, and publishes it to the listeners. Runs until the task is dropped.
+///
+/// A configuration that fails to load, such as a certificate written before its key, is logged
+/// and the previous one kept until the files change again.
+pub async fn watch_certificates(
+    config: TlsConfig,
+    sender: watch::Sender<Arc<ServerConfig>>,
+    poll_interval: Duration,
+) {
+    let paths = [
+        Some(config.cert_path.as_str()),
+        Some(config.key_path.as_str()),
+        config.client_ca_path.as_deref(),
+    ];
+    let modified_times = || {
+        paths
+            .iter()
+            .flatten()
+            .map(|path| {
+                std::fs::metadata(Path::new(path))
+                    .and_then(|metadata| metadata.modified())
+                    .ok()
+            })
+            .collect::<Vec<Option<SystemTime>>>()
+    };
+
+    let mut last_modified = modified_times();
+    let mut interval = tokio::time::interval(poll_interval);
+
+    loop {
+        interval.tick().await;
+
+        let modified = modified_times();
+
+        if modified == last_modified {
+            continue;
+        }
+
+        last_modified = modified;
+
+        match load_server_config(&config) {
+            Ok(server_config) => {
+                sender.send_replace(server_config);
+
+                tracing::info!("TLS certificates reloaded");
+            }
+            Err(err) => {
+                tracing::error!(
+                    error = %err,
+                    "Could not reload TLS certificates, keeping the previous ones"
+                );
+            }
+        }
+    }
+}
+
+/// Accepts TCP connections and completes their TLS handshake with the latest published
+/// configuration before handing them to the server.
+///
+/// Handshakes run in their own tasks, so a slow or silent client cannot hold up the others.
+pub struct TlsListener {
+    local_addr: SocketAddr,
+    connections: mpsc::Receiver<(TlsStream<TcpStream>, SocketAddr)>,
+    accept_task: JoinHandle<()>,
+}
+
+impl TlsListener {
+    /// # Errors
+    ///
+    /// Returns an error if the address `listener` is bound to cannot be read.
+    pub fn new(
+        listener: TcpListener,
+        config: watch::Receiver<Arc<ServerConfig>>,
+    ) -> io::Result<Self> {
+        let local_addr = listener.local_addr()?;
+        let (sender, connections) = mpsc::channel(PENDING_CONNECTIONS);
+
+        Ok(Self {
+            local_addr,
+            connections,
+            accept_task: tokio::spawn(accept_connections(listener, config, sender)),
+        })
+    }
+}
+
+impl Drop for TlsListener {
+    fn drop(&mut self) {
+        self.accept_task.abort();
+    }
+}
+
+impl Listener for TlsListener {
+    type Io = TlsStream<TcpStream>;
+    type Addr = SocketAddr;
+
+    async fn accept(&mut self) -> (Self::Io, Self::Addr) {
+        match self.connections.recv().await {
+            Some(connection) => connection,
+            None => std::future::pending().await,
+        }
+    }
+
+    fn local_addr(&self) -> io::Result<Self::Addr> {
+        Ok(self.local_addr)
+    }
+}
+
+async fn accept_connections(
+    listener: TcpListener,
+    config: watch::Receiver<Arc<ServerConfig>>,
+    sender: mpsc::Sender<(TlsStream<TcpStream>, SocketAddr)>,
+) {
+    loop {
+        let (stream, address) = match listener.accept().await {
+            Ok(connection) => connection,
+            Err(err) => {
+                tracing::warn!(error = %err, "Could not accept TCP connection");
+
+                tokio::time::sleep(Duration::from_millis(50)).await;
+
+                continue;
+            }
+        };
+
+        let acceptor = TlsAcceptor::from(config.borrow().clone());
+        let sender = sender.clone();
+
+        tokio::spawn(async move {
+            match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+                Ok(Ok(stream)) => {
+                    let _ = sender.send((stream, address)).await;
+                }
+                Ok(Err(err)) => {
+                    tracing::debug!(peer = %address, error = %err, "TLS handshake failed");
+                }
+                Err(_) => tracing::debug!(peer = %address, "TLS handshake timed out"),
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, sync::Arc, time::Duration};
+
+    use axum::{Router, routing::get};
+    use rcgen::{CertifiedKey, generate_simple_self_signed};
+    use rustls::{
+        ClientConfig, RootCertStore,
+        crypto::ring,
+        pki_types::{PrivateKeyDer, ServerName, pem::PemObject},
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+        sync::watch,
+    };
+    use tokio_rustls::TlsConnector;
+
+    use crate::infrastructure::http::tls::{
+        ClientAuth, TlsConfig, TlsListener, load_server_config, watch_certificates,
+    };
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("axum_tdd_api_{name}_{}", std::process::id()));
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        std::fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    fn write_certificate(dir: &Path, name: &str) -> CertifiedKey<rcgen::KeyPair> {
+        let certified = generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+
+        std::fs::write(dir.join(format!("{name}.crt")), certified.cert.pem()).unwrap();
+        std::fs::write(
+            dir.join(format!("{name}.key")),
+            certified.signing_key.serialize_pem(),
+        )
+        .unwrap();
+
+        certified
+    }
+
+    async fn serve(
+        config: &TlsConfig,
+    ) -> (
+        std::net::SocketAddr,
+        watch::Sender<Arc<rustls::ServerConfig>>,
+    ) {
+        let (sender, receiver) = watch::channel(load_server_config(config).unwrap());
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let listener = TlsListener::new(listener, receiver).unwrap();
+        let router = Router::new().route("/", get(|| async { "secure" }));
+
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+        (address, sender)
+    }
+
+    /// Sends a request trusting only `trusted`, presenting `client` if given, and returns the
+    /// response, or `None` if the connection was refused.
+    async fn request(
+        address: std::net::SocketAddr,
+        trusted: &CertifiedKey<rcgen::KeyPair>,
+        client: Option<&CertifiedKey<rcgen::KeyPair>>,
+    ) -> Option<String> {
+        let mut roots = RootCertStore::empty();
+
+        roots.add(trusted.cert.der().clone()).unwrap();
+
+        let builder = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_root_certificates(roots);
+        let client_config = match client {
+            Some(client) => builder
+                .with_client_auth_cert(
+                    vec![client.cert.der().clone()],
+                    PrivateKeyDer::from_pem_slice(client.signing_key.serialize_pem().as_bytes())
+                        .unwrap(),
+                )
+                .unwrap(),
+            None => builder.with_no_client_auth(),
+        };
+
+        let stream = TcpStream::connect(address).await.unwrap();
+        let mut stream = TlsConnector::from(Arc::new(client_config))
+            .connect(ServerName::try_from("localhost").unwrap(), stream)
+            .await
+            .ok()?;
+
+        stream
+            .write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .ok()?;
+
+        let mut response = String::new();
+
+        stream.read_to_string(&mut response).await.ok()?;
+
+        Some(response).filter(|response| !response.is_empty())
+    }
+
+    #[tokio::test]
+    async fn should_serve_https_and_pick_up_renewed_certificate() {
+        let dir = temp_dir("tls_reload");
+        let first = write_certificate(&dir, "server");
+        let config = TlsConfig {
+            cert_path: dir.join("server.crt").to_str().unwrap().to_string(),
+            key_path: dir.join("server.key").to_str().unwrap().to_string(),
+            client_ca_path: None,
+            client_auth: ClientAuth::Required,
+            redirect_port: None,
+        };
+
+        let (address, sender) = serve(&config).await;
+
+        tokio::spawn(watch_certificates(
+            config,
+            sender,
+            Duration::from_millis(20),
+        ));
+
+        assert!(
+            request(address, &first, None)
+                .await
+                .unwrap()
+                .ends_with("secure")
+        );
+
+        let renewed = write_certificate(&dir, "server");
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert!(
+            request(address, &renewed, None)
+                .await
+                .unwrap()
+                .ends_with("secure")
+        );
+        assert_eq!(request(address, &first, None).await, None);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn should_require_client_certificate_signed_by_trusted_ca() {
+        let dir = temp_dir("tls_mutual");
+        let server = write_certificate(&dir, "server");
+        let client = write_certificate(&dir, "client");
+        let stranger = write_certificate(&dir, "stranger");
+
+        let (address, _sender) = serve(&TlsConfig {
+            cert_path: dir.join("server.crt").to_str().unwrap().to_string(),
+            key_path: dir.join("server.key").to_str().unwrap().to_string(),
+            client_ca_path: Some(dir.join("client.crt").to_str().unwrap().to_string()),
+            client_auth: ClientAuth::Required,
+            redirect_port: None,
+        })
+        .await;
+
+        assert!(
+            request(address, &server, Some(&client))
+                .await
+                .unwrap()
+                .ends_with("secure")
+        );
+        assert_eq!(request(address, &server, Some(&stranger)).await, None);
+        assert_eq!(request(address, &server, None).await, None);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
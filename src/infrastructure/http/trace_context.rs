@@ -0,0 +1,152 @@
+use std::str::FromStr;
+
+use axum::http::HeaderMap;
+use tracing::Span;
+
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+tokio::task_local! {
+    static CURRENT_TRACE_ID: String;
+}
+
+/// The parts of a W3C `traceparent` header (`00-<trace id>-<parent id>-<flags>`) this service
+/// relies on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceParent {
+    pub trace_id: String,
+    pub parent_id: String,
+}
+
+impl FromStr for TraceParent {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let is_hex_id = |part: &str, len: usize| {
+            part.len() == len
+                && part
+                    .chars()
+                    .all(|char| matches!(char, '0'..='9' | 'a'..='f'))
+                && part.chars().any(|char| char != '0')
+        };
+
+        match value.trim().split('-').collect::<Vec<_>>().as_slice() {
+            [version, trace_id, parent_id, flags]
+                if *version != "ff"
+                    && version.len() == 2
+                    && is_hex_id(trace_id, 32)
+                    && is_hex_id(parent_id, 16)
+                    && flags.len() == 2 =>
+            {
+                Ok(Self {
+                    trace_id: (*trace_id).to_string(),
+                    parent_id: (*parent_id).to_string(),
+                })
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+/// Reads the trace the caller propagated in `headers`, if it sent a valid `traceparent`.
+#[must_use]
+pub fn incoming_trace_parent(headers: &HeaderMap) -> Option<TraceParent> {
+    headers
+        .get(TRACEPARENT_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+}
+
+/// Links `span` to the trace the caller propagated in `headers` and returns the id of the trace
+/// the request belongs to, starting a new one if the caller sent none.
+#[must_use]
+pub fn join_trace(span: &Span, headers: &HeaderMap) -> String {
+    exported_trace_id(span, headers).unwrap_or_else(|| {
+        incoming_trace_parent(headers)
+            .map_or_else(generate_trace_id, |trace_parent| trace_parent.trace_id)
+    })
+}
+
+/// With span export enabled, the trace is the one OpenTelemetry records `span` under.
+#[cfg(feature = "otlp")]
+fn exported_trace_id(span: &Span, headers: &HeaderMap) -> Option<String> {
+    use opentelemetry::{
+        propagation::TextMapPropagator,
+        trace::{TraceContextExt, TraceId},
+    };
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    let parent = TraceContextPropagator::new().extract(&HeaderExtractor(headers));
+
+    // Fails when no exporting layer is installed, in which case the span has no trace of its own.
+    span.set_parent(parent).ok()?;
+
+    let trace_id = span.context().span().span_context().trace_id();
+
+    (trace_id != TraceId::INVALID).then(|| trace_id.to_string())
+}
+
+#[cfg(not(feature = "otlp"))]
+const fn exported_trace_id(_span: &Span, _headers: &HeaderMap) -> Option<String> {
+    None
+}
+
+#[cfg(feature = "otlp")]
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+#[cfg(feature = "otlp")]
+impl opentelemetry::propagation::Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(axum::http::HeaderName::as_str).collect()
+    }
+}
+
+/// A random trace id, for requests that did not arrive as part of a trace.
+#[must_use]
+pub fn generate_trace_id() -> String {
+    format!("{:032x}", rand::random::<u128>().max(1))
+}
+
+/// Runs `future` with `trace_id` as the trace of the request being handled.
+pub async fn with_trace_id<F: Future>(trace_id: String, future: F) -> F::Output {
+    CURRENT_TRACE_ID.scope(trace_id, future).await
+}
+
+/// The trace id of the request being handled, if called while handling one.
+#[must_use]
+pub fn current_trace_id() -> Option<String> {
+    CURRENT_TRACE_ID.try_with(Clone::clone).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::http::trace_context::{TraceParent, generate_trace_id};
+
+    #[test]
+    fn should_parse_valid_traceparent_and_reject_malformed_ones() {
+        assert_eq!(
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".parse(),
+            Ok(TraceParent {
+                trace_id: "4bf92f3577b34da6a3ce929d0e0e4736".to_string(),
+                parent_id: "00f067aa0ba902b7".to_string(),
+            })
+        );
+
+        for invalid in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+        ] {
+            assert_eq!(invalid.parse::<TraceParent>(), Err(()), "{invalid}");
+        }
+
+        assert_eq!(generate_trace_id().len(), 32);
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt;
+
+use serde_json::{Map, Value};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::{
+    fmt::{
+        FmtContext, FormatEvent, FormatFields, FormattedFields,
+        format::Writer,
+        time::{FormatTime, SystemTime},
+    },
+    registry::LookupSpan,
+};
+
+use crate::infrastructure::logging::redaction::JsonVisitor;
+
+/// Writes one JSON document per event, with the fields of every enclosing span.
+///
+/// Span fields must have been formatted by
+/// [`RedactingJsonFields`](crate::infrastructure::logging::redaction::RedactingJsonFields).
+pub struct JsonEventFormat;
+
+impl<S, N> FormatEvent<S, N> for JsonEventFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let mut timestamp = String::new();
+
+        SystemTime.format_time(&mut Writer::new(&mut timestamp))?;
+
+        let mut fields = Map::new();
+
+        event.record(&mut JsonVisitor(&mut fields));
+
+        let spans = ctx
+            .event_scope()
+            .map(|scope| {
+                scope
+                    .from_root()
+                    .map(|span| {
+                        let mut object = span
+                            .extensions()
+                            .get::<FormattedFields<N>>()
+                            .and_then(|formatted| {
+                                serde_json::from_str::<Map<String, Value>>(formatted).ok()
+                            })
+                            .unwrap_or_default();
+
+                        object.insert("name".to_string(), Value::from(span.name()));
+
+                        Value::Object(object)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let mut line = Map::new();
+
+        line.insert("timestamp".to_string(), Value::from(timestamp));
+        line.insert(
+            "level".to_string(),
+            Value::from(event.metadata().level().as_str()),
+        );
+        line.insert("target".to_string(), Value::from(event.metadata().target()));
+        line.insert("fields".to_string(), Value::Object(fields));
+        line.insert("spans".to_string(), Value::Array(spans));
+
+        writeln!(writer, "{}", Value::Object(line))
+    }
+}
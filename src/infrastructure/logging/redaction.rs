@@ -0,0 +1,252 @@
+use std::fmt;
+
+use serde_json::{Map, Value};
+use tracing::field::{Field, Visit};
+use tracing_subscriber::{
+    field::RecordFields,
+    fmt::{FormatFields, FormattedFields, format::Writer},
+};
+
+use crate::domain::values::secret::REDACTED;
+
+/// Field names that never reach the output with their value, matched case-insensitively anywhere
+/// in the name, so `password_confirmation` and `admin_api_token` are covered too.
+const SENSITIVE_FIELD_NAMES: [&str; 6] = [
+    "password",
+    "token",
+    "secret",
+    "authorization",
+    "cookie",
+    "api_key",
+];
+
+#[must_use]
+pub fn is_sensitive(field_name: &str) -> bool {
+    let field_name = field_name.to_lowercase();
+
+    SENSITIVE_FIELD_NAMES
+        .iter()
+        .any(|sensitive| field_name.contains(sensitive))
+}
+
+/// Formats fields as `name=value` pairs, replacing the value of sensitive fields.
+pub struct RedactingTextFields;
+
+impl<'writer> FormatFields<'writer> for RedactingTextFields {
+    fn format_fields<R: RecordFields>(&self, writer: Writer<'writer>, fields: R) -> fmt::Result {
+        let mut visitor = TextVisitor {
+            writer,
+            result: Ok(()),
+            is_first: true,
+        };
+
+        fields.record(&mut visitor);
+
+        visitor.result
+    }
+}
+
+struct TextVisitor<'writer> {
+    writer: Writer<'writer>,
+    result: fmt::Result,
+    is_first: bool,
+}
+
+impl Visit for TextVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if self.result.is_err() {
+            return;
+        }
+
+        let delimiter = if self.is_first { "" } else { " " };
+
+        self.is_first = false;
+        self.result = if field.name() == "message" {
+            write!(self.writer, "{delimiter}{value:?}")
+        } else if is_sensitive(field.name()) {
+            write!(self.writer, "{delimiter}{}={REDACTED}", field.name())
+        } else {
+            write!(self.writer, "{delimiter}{}={value:?}", field.name())
+        };
+    }
+}
+
+/// Formats fields as a JSON object, replacing the value of sensitive fields. Used for span fields
+/// when logging JSON lines, which [`JsonEventFormat`] reads back.
+///
+/// [`JsonEventFormat`]: crate::infrastructure::logging::json_format::JsonEventFormat
+pub struct RedactingJsonFields;
+
+impl<'writer> FormatFields<'writer> for RedactingJsonFields {
+    fn format_fields<R: RecordFields>(
+        &self,
+        mut writer: Writer<'writer>,
+        fields: R,
+    ) -> fmt::Result {
+        let mut object = Map::new();
+
+        fields.record(&mut JsonVisitor(&mut object));
+
+        write!(writer, "{}", Value::Object(object))
+    }
+
+    /// Merges fields recorded after the span was created into its existing JSON object, instead
+    /// of appending them as the default implementation does.
+    fn add_fields(
+        &self,
+        current: &'writer mut FormattedFields<Self>,
+        fields: &tracing::span::Record<'_>,
+    ) -> fmt::Result {
+        let mut object =
+            serde_json::from_str::<Map<String, Value>>(&current.fields).unwrap_or_default();
+
+        fields.record(&mut JsonVisitor(&mut object));
+
+        current.fields = Value::Object(object).to_string();
+
+        Ok(())
+    }
+}
+
+/// Collects fields into a JSON object, replacing the value of sensitive fields.
+pub struct JsonVisitor<'a>(pub &'a mut Map<String, Value>);
+
+impl JsonVisitor<'_> {
+    fn insert(&mut self, field: &Field, value: Value) {
+        let value = if is_sensitive(field.name()) {
+            Value::from(REDACTED)
+        } else {
+            value
+        };
+
+        self.0.insert(field.name().to_string(), value);
+    }
+}
+
+impl Visit for JsonVisitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.insert(field, Value::from(format!("{value:?}")));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use serde_json::Value;
+    use tracing_subscriber::{fmt::MakeWriter, layer::SubscriberExt};
+
+    use crate::infrastructure::logging::{
+        json_format::JsonEventFormat,
+        redaction::{RedactingJsonFields, RedactingTextFields},
+    };
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for Buffer {
+        fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(bytes);
+
+            Ok(bytes.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    impl Buffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    fn log_sign_in() {
+        let span = tracing::info_span!(
+            "http_request",
+            route = "/sign-in",
+            authorization = "Bearer abc",
+            status = tracing::field::Empty,
+        );
+        let _entered = span.enter();
+
+        span.record("status", 200);
+
+        tracing::info!(
+            email = "john.doe@mail.com",
+            password = "SuperSecret123",
+            "signed in"
+        );
+    }
+
+    #[test]
+    fn should_redact_sensitive_fields_in_text_and_json_output() {
+        let text = Buffer::default();
+        let json = Buffer::default();
+
+        tracing::subscriber::with_default(
+            tracing_subscriber::registry().with(
+                tracing_subscriber::fmt::layer()
+                    .fmt_fields(RedactingTextFields)
+                    .with_ansi(false)
+                    .with_writer(text.clone()),
+            ),
+            log_sign_in,
+        );
+
+        tracing::subscriber::with_default(
+            tracing_subscriber::registry().with(
+                tracing_subscriber::fmt::layer()
+                    .fmt_fields(RedactingJsonFields)
+                    .event_format(JsonEventFormat)
+                    .with_writer(json.clone()),
+            ),
+            log_sign_in,
+        );
+
+        let text = text.contents();
+
+        assert!(text.contains("authorization=[REDACTED]"));
+        assert!(text.contains("signed in email=\"john.doe@mail.com\" password=[REDACTED]"));
+        assert!(!text.contains("SuperSecret123"));
+        assert!(!text.contains("Bearer abc"));
+
+        let line = serde_json::from_str::<Value>(&json.contents()).unwrap();
+
+        assert_eq!(line["fields"]["message"], "signed in");
+        assert_eq!(line["fields"]["email"], "john.doe@mail.com");
+        assert_eq!(line["fields"]["password"], "[REDACTED]");
+        assert_eq!(line["spans"][0]["name"], "http_request");
+        assert_eq!(line["spans"][0]["authorization"], "[REDACTED]");
+        assert_eq!(line["spans"][0]["status"], 200);
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    domain::{
+        dtos::user::FindUserByEmailDto,
+        entities::user::UserEntity,
+        errors::domain::DomainError,
+        repositories::{
+            unit_of_work::{TransactionPort, UnitOfWorkPort},
+            user::UserPersistencePort,
+        },
+    },
+    infrastructure::repositories::in_memory::user::{
+        InMemoryUserRepository, UserStore, ensure_unique_email, lock_users,
+    },
+};
+
+pub struct InMemoryUnitOfWork {
+    users: UserStore,
+}
+
+impl InMemoryUnitOfWork {
+    #[must_use]
+    pub fn new(repository: &InMemoryUserRepository) -> Self {
+        Self {
+            users: repository.store(),
+        }
+    }
+}
+
+impl UnitOfWorkPort for InMemoryUnitOfWork {
+    fn begin(&self) -> Result<Box<dyn TransactionPort>, DomainError> {
+        Ok(Box::new(InMemoryTransaction {
+            users: Arc::new(StagedUserRepository {
+                committed: Arc::clone(&self.users),
+                staged: Mutex::new(Vec::new()),
+            }),
+        }))
+    }
+}
+
+struct InMemoryTransaction {
+    users: Arc<StagedUserRepository>,
+}
+
+impl TransactionPort for InMemoryTransaction {
+    fn users(&self) -> Arc<dyn UserPersistencePort> {
+        self.users.clone()
+    }
+
+    fn commit(&self) -> Result<(), DomainError> {
+        let mut staged = lock_users(&self.users.staged)?;
+        let mut committed = lock_users(&self.users.committed)?;
+
+        for user in staged.iter() {
+            ensure_unique_email(&committed, user.email())?;
+        }
+
+        committed.append(&mut staged);
+        drop(committed);
+        drop(staged);
+
+        Ok(())
+    }
+
+    fn rollback(&self) -> Result<(), DomainError> {
+        lock_users(&self.users.staged)?.clear();
+
+        Ok(())
+    }
+}
+
+/// Buffers writes until the owning transaction commits, while reads see both the buffered and the
+/// already committed users. Uniqueness is checked again on commit, since a concurrent transaction
+/// may have claimed the same e-mail in between.
+struct StagedUserRepository {
+    committed: UserStore,
+    staged: Mutex<Vec<UserEntity>>,
+}
+
+impl UserPersistencePort for StagedUserRepository {
+    fn create(&self, user: UserEntity) -> Result<UserEntity, DomainError> {
+        let mut staged = lock_users(&self.staged)?;
+
+        ensure_unique_email(&staged, user.email())?;
+        ensure_unique_email(&lock_users(&self.committed)?, user.email())?;
+
+        staged.push(user.clone());
+        drop(staged);
+
+        Ok(user)
+    }
+
+    fn find_by_email(&self, dto: FindUserByEmailDto) -> Result<Option<UserEntity>, DomainError> {
+        let staged = lock_users(&self.staged)?;
+
+        if let Some(user) = staged.iter().find(|user| user.email() == dto.email) {
+            return Ok(Some(user.clone()));
+        }
+
+        drop(staged);
+
+        let committed = lock_users(&self.committed)?;
+
+        Ok(committed
+            .iter()
+            .find(|user| user.email() == dto.email)
+            .cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        infrastructure::repositories::in_memory::{
+            unit_of_work::InMemoryUnitOfWork, user::InMemoryUserRepository,
+        },
+        test_support::{
+            concurrent_sign_up::assert_single_concurrent_sign_up_succeeds,
+            contracts::unit_of_work::unit_of_work_contract,
+        },
+    };
+
+    unit_of_work_contract!(|_| {
+        let repository = InMemoryUserRepository::new();
+
+        (InMemoryUnitOfWork::new(&repository), repository)
+    });
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn should_accept_exactly_one_of_concurrent_sign_ups() {
+        let repository = InMemoryUserRepository::new();
+
+        assert_single_concurrent_sign_up_succeeds(Arc::new(InMemoryUnitOfWork::new(&repository)))
+            .await;
+    }
+}
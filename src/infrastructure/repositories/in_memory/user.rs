@@ -0,0 +1,87 @@
+use std::sync::{Arc, Mutex};
+
+use crate::domain::{
+    dtos::user::FindUserByEmailDto, entities::user::UserEntity, errors::domain::DomainError,
+    repositories::user::UserPersistencePort,
+};
+
+pub type UserStore = Arc<Mutex<Vec<UserEntity>>>;
+
+pub struct InMemoryUserRepository {
+    users: UserStore,
+}
+
+impl InMemoryUserRepository {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            users: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    #[must_use]
+    pub fn store(&self) -> UserStore {
+        Arc::clone(&self.users)
+    }
+}
+
+impl UserPersistencePort for InMemoryUserRepository {
+    fn create(&self, user: UserEntity) -> Result<UserEntity, DomainError> {
+        let mut users = lock_users(&self.users)?;
+
+        ensure_unique_email(&users, user.email())?;
+
+        users.push(user.clone());
+        drop(users);
+
+        Ok(user)
+    }
+
+    fn find_by_email(&self, dto: FindUserByEmailDto) -> Result<Option<UserEntity>, DomainError> {
+        let users = lock_users(&self.users)?;
+
+        Ok(users.iter().find(|user| user.email() == dto.email).cloned())
+    }
+}
+
+impl Default for InMemoryUserRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Locks a user store, turning a poisoned lock into a domain error.
+///
+/// # Errors
+///
+/// Returns a [`DomainError::Internal`] if another thread panicked while holding the lock.
+pub fn lock_users(
+    users: &Mutex<Vec<UserEntity>>,
+) -> Result<std::sync::MutexGuard<'_, Vec<UserEntity>>, DomainError> {
+    users
+        .lock()
+        .map_err(|_| DomainError::Internal("In-memory user store is poisoned".to_string()))
+}
+
+/// Rejects `email` if any of `users` already owns it.
+///
+/// # Errors
+///
+/// Returns a [`DomainError::Conflict`] naming the `email` field when it is already taken.
+pub fn ensure_unique_email(users: &[UserEntity], email: &str) -> Result<(), DomainError> {
+    if users.iter().any(|user| user.email() == email) {
+        return Err(DomainError::Conflict("email".to_string()));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        infrastructure::repositories::in_memory::user::InMemoryUserRepository,
+        test_support::contracts::user_persistence::user_persistence_contract,
+    };
+
+    user_persistence_contract!(InMemoryUserRepository::new);
+}
@@ -0,0 +1,90 @@
+use std::{sync::Arc, time::Instant};
+
+use crate::{
+    application::ports::adapters::metrics::MetricsPort,
+    domain::{
+        dtos::user::FindUserByEmailDto,
+        entities::user::UserEntity,
+        errors::domain::DomainError,
+        repositories::{
+            unit_of_work::{TransactionPort, UnitOfWorkPort},
+            user::UserPersistencePort,
+        },
+    },
+};
+
+/// Wraps any unit of work so every repository and transaction call reports its latency, whatever
+/// the storage behind it.
+pub struct MeasuredUnitOfWork {
+    inner: Arc<dyn UnitOfWorkPort>,
+    metrics: Arc<dyn MetricsPort>,
+}
+
+impl MeasuredUnitOfWork {
+    #[must_use]
+    pub fn new(inner: Arc<dyn UnitOfWorkPort>, metrics: Arc<dyn MetricsPort>) -> Self {
+        Self { inner, metrics }
+    }
+}
+
+impl UnitOfWorkPort for MeasuredUnitOfWork {
+    fn begin(&self) -> Result<Box<dyn TransactionPort>, DomainError> {
+        let inner = measure(self.metrics.as_ref(), "begin", || self.inner.begin())?;
+
+        Ok(Box::new(MeasuredTransaction {
+            users: Arc::new(MeasuredUserRepository {
+                inner: inner.users(),
+                metrics: self.metrics.clone(),
+            }),
+            inner,
+            metrics: self.metrics.clone(),
+        }))
+    }
+}
+
+struct MeasuredTransaction {
+    inner: Box<dyn TransactionPort>,
+    users: Arc<MeasuredUserRepository>,
+    metrics: Arc<dyn MetricsPort>,
+}
+
+impl TransactionPort for MeasuredTransaction {
+    fn users(&self) -> Arc<dyn UserPersistencePort> {
+        self.users.clone()
+    }
+
+    fn commit(&self) -> Result<(), DomainError> {
+        measure(self.metrics.as_ref(), "commit", || self.inner.commit())
+    }
+
+    fn rollback(&self) -> Result<(), DomainError> {
+        measure(self.metrics.as_ref(), "rollback", || self.inner.rollback())
+    }
+}
+
+struct MeasuredUserRepository {
+    inner: Arc<dyn UserPersistencePort>,
+    metrics: Arc<dyn MetricsPort>,
+}
+
+impl UserPersistencePort for MeasuredUserRepository {
+    fn create(&self, user: UserEntity) -> Result<UserEntity, DomainError> {
+        measure(self.metrics.as_ref(), "create", || self.inner.create(user))
+    }
+
+    fn find_by_email(&self, dto: FindUserByEmailDto) -> Result<Option<UserEntity>, DomainError> {
+        measure(self.metrics.as_ref(), "find_by_email", || {
+            self.inner.find_by_email(dto)
+        })
+    }
+}
+
+fn measure<T>(metrics: &dyn MetricsPort, operation: &'static str, call: impl FnOnce() -> T) -> T {
+    let _span = tracing::debug_span!("repository_call", operation).entered();
+    let started_at = Instant::now();
+    let result = call();
+
+    metrics.observe_repository_call(operation, started_at.elapsed());
+
+    result
+}
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        errors::domain::DomainError,
+        repositories::{
+            unit_of_work::{TransactionPort, UnitOfWorkPort},
+            user::UserPersistencePort,
+        },
+    },
+    infrastructure::repositories::sqlite::user::SqliteUserRepository,
+};
+
+/// Opens a dedicated connection per transaction, so `path` must point to a database file rather
+/// than `:memory:`.
+pub struct SqliteUnitOfWork {
+    path: String,
+}
+
+impl SqliteUnitOfWork {
+    #[must_use]
+    pub const fn new(path: String) -> Self {
+        Self { path }
+    }
+}
+
+impl UnitOfWorkPort for SqliteUnitOfWork {
+    fn begin(&self) -> Result<Box<dyn TransactionPort>, DomainError> {
+        let users = SqliteUserRepository::open(&self.path)?;
+
+        users.execute_batch("BEGIN IMMEDIATE")?;
+
+        Ok(Box::new(SqliteTransaction {
+            users: Arc::new(users),
+        }))
+    }
+}
+
+struct SqliteTransaction {
+    users: Arc<SqliteUserRepository>,
+}
+
+impl TransactionPort for SqliteTransaction {
+    fn users(&self) -> Arc<dyn UserPersistencePort> {
+        self.users.clone()
+    }
+
+    fn commit(&self) -> Result<(), DomainError> {
+        self.users.execute_batch("COMMIT")
+    }
+
+    fn rollback(&self) -> Result<(), DomainError> {
+        self.users.execute_batch("ROLLBACK")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        infrastructure::repositories::sqlite::{
+            unit_of_work::SqliteUnitOfWork, user::SqliteUserRepository,
+        },
+        test_support::{
+            concurrent_sign_up::assert_single_concurrent_sign_up_succeeds,
+            contracts::unit_of_work::unit_of_work_contract,
+        },
+    };
+
+    unit_of_work_contract!(|path| {
+        let path = path.to_string_lossy().into_owned();
+        let store = SqliteUserRepository::open(&path).unwrap();
+
+        (SqliteUnitOfWork::new(path), store)
+    });
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn should_accept_exactly_one_of_concurrent_sign_ups() {
+        let path = std::env::temp_dir().join(format!(
+            "axum_tdd_api_concurrent_sign_up_{}.db",
+            std::process::id()
+        ));
+
+        let _ = std::fs::remove_file(&path);
+
+        assert_single_concurrent_sign_up_succeeds(Arc::new(SqliteUnitOfWork::new(
+            path.to_string_lossy().into_owned(),
+        )))
+        .await;
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,258 @@
+use std::{sync::Mutex, time::Duration};
+
+use rusqlite::{Connection, ErrorCode, OptionalExtension, params};
+
+use crate::domain::{
+    dtos::user::FindUserByEmailDto,
+    entities::user::{StoredUser, UserEntity},
+    errors::domain::DomainError,
+    repositories::user::UserPersistencePort,
+    values::timestamp::Timestamp,
+};
+
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Version recorded in `PRAGMA user_version` once [`MIGRATIONS`] have run: one per migration.
+pub const SCHEMA_VERSION: i64 = 3;
+
+/// Schema changes in order, the one at index `n` taking the schema from version `n` to `n + 1`.
+const MIGRATIONS: &[&str] = &[
+    "
+    CREATE TABLE IF NOT EXISTS users (
+        id TEXT PRIMARY KEY NOT NULL,
+        first_name TEXT NOT NULL,
+        last_name TEXT NOT NULL,
+        email TEXT NOT NULL,
+        password_hash TEXT NOT NULL,
+        created_at INTEGER NOT NULL,
+        updated_at INTEGER NOT NULL
+    );
+
+    CREATE UNIQUE INDEX IF NOT EXISTS users_email_unique ON users (email);
+    ",
+    // Timestamps were stored in seconds before they gained millisecond precision.
+    "
+    UPDATE users SET created_at = created_at * 1000, updated_at = updated_at * 1000;
+    ",
+    "
+    ALTER TABLE users ADD COLUMN email_verified_at INTEGER;
+    ",
+];
+
+pub struct SqliteUserRepository {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteUserRepository {
+    /// Opens the database at `path` and makes sure its schema is up to date.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DomainError::Internal`] if the database cannot be opened or migrated.
+    pub fn open(path: &str) -> Result<Self, DomainError> {
+        let connection =
+            Connection::open(path).map_err(|err| DomainError::Internal(err.to_string()))?;
+
+        connection
+            .busy_timeout(BUSY_TIMEOUT)
+            .map_err(|err| DomainError::Internal(err.to_string()))?;
+
+        migrate(&connection).map_err(|err| DomainError::Internal(err.to_string()))?;
+
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    /// Runs raw SQL statements on the underlying connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DomainError::Internal`] if any statement fails.
+    pub fn execute_batch(&self, sql: &str) -> Result<(), DomainError> {
+        self.lock_connection()?
+            .execute_batch(sql)
+            .map_err(|err| DomainError::Internal(err.to_string()))
+    }
+
+    fn lock_connection(&self) -> Result<std::sync::MutexGuard<'_, Connection>, DomainError> {
+        self.connection
+            .lock()
+            .map_err(|_| DomainError::Internal("SQLite connection is poisoned".to_string()))
+    }
+}
+
+/// Runs the migrations the database has not seen yet, each in its own transaction along with the
+/// version bump, so an interrupted upgrade resumes where it stopped.
+///
+/// Connections opened at the same time on an outdated file race to migrate it, so the version is
+/// read again once the write lock is held, and migrations another connection ran are skipped.
+fn migrate(connection: &Connection) -> rusqlite::Result<()> {
+    if schema_version(connection)? >= MIGRATIONS.len() {
+        return Ok(());
+    }
+
+    for (index, migration) in MIGRATIONS.iter().enumerate() {
+        connection.execute_batch("BEGIN IMMEDIATE")?;
+
+        let result = schema_version(connection).and_then(|version| {
+            if version > index {
+                return Ok(());
+            }
+
+            connection.execute_batch(&format!("{migration} PRAGMA user_version = {};", index + 1))
+        });
+
+        match result {
+            Ok(()) => connection.execute_batch("COMMIT")?,
+            Err(err) => {
+                // The original failure matters more than one rolling back.
+                let _ = connection.execute_batch("ROLLBACK");
+
+                return Err(err);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn schema_version(connection: &Connection) -> rusqlite::Result<usize> {
+    connection
+        .query_row("PRAGMA user_version", [], |row| row.get::<_, u32>(0))
+        .map(|version| usize::try_from(version).unwrap_or(usize::MAX))
+}
+
+impl UserPersistencePort for SqliteUserRepository {
+    fn create(&self, user: UserEntity) -> Result<UserEntity, DomainError> {
+        self.lock_connection()?
+            .execute(
+                "INSERT INTO users (id, first_name, last_name, email, password_hash, email_verified_at, created_at, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    user.id(),
+                    user.first_name(),
+                    user.last_name(),
+                    user.email(),
+                    user.password_hash(),
+                    user.email_verified_at().map(Timestamp::unix_millis),
+                    user.created_at().unix_millis(),
+                    user.updated_at().unix_millis(),
+                ],
+            )
+            .map_err(|err| match &err {
+                rusqlite::Error::SqliteFailure(failure, Some(message))
+                    if failure.code == ErrorCode::ConstraintViolation
+                        && message.contains("users.email") =>
+                {
+                    DomainError::Conflict("email".to_string())
+                }
+                _ => DomainError::Internal(err.to_string()),
+            })?;
+
+        Ok(user)
+    }
+
+    fn find_by_email(&self, dto: FindUserByEmailDto) -> Result<Option<UserEntity>, DomainError> {
+        self.lock_connection()?
+            .query_row(
+                "SELECT id, first_name, last_name, email, password_hash, email_verified_at, created_at, updated_at
+                 FROM users WHERE email = ?1",
+                params![dto.email],
+                |row| {
+                    Ok(UserEntity::restore(StoredUser {
+                        id: row.get(0)?,
+                        first_name: row.get(1)?,
+                        last_name: row.get(2)?,
+                        email: row.get(3)?,
+                        password_hash: row.get(4)?,
+                        email_verified_at: row
+                            .get::<_, Option<i64>>(5)?
+                            .map(Timestamp::from_unix_millis),
+                        created_at: Timestamp::from_unix_millis(row.get(6)?),
+                        updated_at: Timestamp::from_unix_millis(row.get(7)?),
+                    }))
+                },
+            )
+            .optional()
+            .map_err(|err| DomainError::Internal(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use crate::{
+        domain::{
+            dtos::user::FindUserByEmailDto, repositories::user::UserPersistencePort,
+            values::timestamp::Timestamp,
+        },
+        infrastructure::repositories::sqlite::user::{
+            MIGRATIONS, SCHEMA_VERSION, SqliteUserRepository,
+        },
+        test_support::contracts::user_persistence::user_persistence_contract,
+    };
+
+    user_persistence_contract!(|| SqliteUserRepository::open(":memory:").unwrap());
+
+    #[test]
+    fn should_migrate_users_stored_by_the_first_schema() {
+        let path =
+            std::env::temp_dir().join(format!("axum_tdd_api_users_{}.sqlite", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        let _ = std::fs::remove_file(path);
+
+        Connection::open(path)
+            .unwrap()
+            .execute_batch(&format!(
+                "{} PRAGMA user_version = 1;
+                 INSERT INTO users VALUES
+                     ('user_1', 'John', 'Doe', 'john.doe@mail.com', 'hash', 1700000000, 1700000060);",
+                MIGRATIONS[0]
+            ))
+            .unwrap();
+
+        let repository = SqliteUserRepository::open(path).unwrap();
+        let user = repository
+            .find_by_email(FindUserByEmailDto {
+                email: "john.doe@mail.com".to_string(),
+            })
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            user.created_at(),
+            Timestamp::from_unix_seconds(1_700_000_000)
+        );
+        assert_eq!(
+            user.updated_at(),
+            Timestamp::from_unix_seconds(1_700_000_060)
+        );
+        assert_eq!(user.password_hash(), "hash");
+        assert!(!user.is_email_verified());
+
+        drop(repository);
+
+        // Reopening an up-to-date database must not convert the timestamps again.
+        let version: i64 = Connection::open(path)
+            .unwrap()
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .unwrap();
+
+        assert_eq!(version, SCHEMA_VERSION);
+        assert_eq!(
+            SqliteUserRepository::open(path)
+                .unwrap()
+                .find_by_email(FindUserByEmailDto {
+                    email: "john.doe@mail.com".to_string(),
+                })
+                .unwrap()
+                .map(|user| user.created_at()),
+            Some(Timestamp::from_unix_seconds(1_700_000_000))
+        );
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -2,20 +2,43 @@
 #![deny(clippy::pedantic)]
 #![deny(clippy::nursery)]
 
-use crate::composition::bootstrap::server::Server;
+use std::process::ExitCode;
+
+use crate::composition::{bootstrap::server::Server, cli};
 
 pub mod composition {
     pub mod bootstrap {
+        pub mod app;
+        pub mod logging;
         pub mod server;
+        pub mod shutdown;
+        pub mod telemetry;
+    }
+
+    pub mod cli {
+        pub mod config;
+        pub mod openapi;
+    }
+
+    pub mod config {
+        pub mod app;
+        pub mod listeners;
+        pub mod reloader;
+        pub mod runtime;
+        pub mod settings;
     }
 }
 
 pub mod application {
     pub mod ports {
         pub mod adapters {
+            pub mod audit_log;
             pub mod env;
+            pub mod health_check;
             pub mod id_generator;
+            pub mod metrics;
             pub mod password_hasher;
+            pub mod rate_limit;
             pub mod time;
         }
 
@@ -30,6 +53,12 @@ pub mod application {
         pub mod auth {
             pub mod sign_up;
         }
+
+        pub mod request;
+    }
+
+    pub mod outputs {
+        pub mod user;
     }
 
     pub mod use_cases {
@@ -41,7 +70,124 @@ pub mod application {
 
 pub mod infrastructure {
     pub mod adapters {
-        pub mod dotenvy;
+        pub mod argon2_password_hasher;
+
+        pub mod audit_log {
+            pub mod chain;
+            pub mod in_memory;
+            pub mod json_lines;
+            pub mod sqlite;
+        }
+
+        pub mod health {
+            pub mod disk_space;
+            pub mod smtp;
+            pub mod sqlite;
+        }
+
+        pub mod id_generator {
+            pub mod clock;
+            pub mod random_uuid;
+            pub mod snowflake;
+            pub mod ulid;
+            pub mod uuid;
+            pub mod uuid_v7;
+        }
+
+        pub mod layered_env;
+        pub mod prometheus_metrics;
+
+        pub mod rate_limit {
+            pub mod in_memory;
+            pub mod sqlite;
+        }
+
+        pub mod system_time;
+    }
+
+    pub mod http {
+        pub mod api_version;
+        pub mod date_time;
+
+        pub mod extractors {
+            pub mod json;
+            pub mod request_metadata;
+        }
+
+        pub mod handlers {
+            pub mod admin {
+                pub mod audit_log;
+            }
+
+            pub mod auth {
+                pub mod sign_up;
+            }
+
+            pub mod health;
+            pub mod https_redirect;
+            pub mod metrics;
+            pub mod openapi;
+
+            pub mod v1 {
+                pub mod auth {
+                    pub mod sign_up;
+                }
+            }
+
+            pub mod v2 {
+                pub mod auth {
+                    pub mod sign_up;
+                }
+            }
+        }
+
+        pub mod middlewares {
+            pub mod admin_token;
+            pub mod api_version;
+            pub mod cors;
+            pub mod metrics;
+            pub mod rate_limit;
+            pub mod request_id;
+            pub mod trace;
+        }
+
+        pub mod http_date;
+        pub mod listeners;
+        pub mod openapi;
+        pub mod peer;
+        pub mod problem;
+
+        pub mod routers {
+            pub mod admin;
+            pub mod health;
+            pub mod https_redirect;
+            pub mod metrics;
+            pub mod openapi;
+            pub mod v1;
+            pub mod v2;
+        }
+
+        pub mod tls;
+        pub mod trace_context;
+    }
+
+    pub mod logging {
+        pub mod json_format;
+        pub mod redaction;
+    }
+
+    pub mod repositories {
+        pub mod measured;
+
+        pub mod in_memory {
+            pub mod unit_of_work;
+            pub mod user;
+        }
+
+        pub mod sqlite {
+            pub mod unit_of_work;
+            pub mod user;
+        }
     }
 }
 
@@ -55,19 +201,62 @@ pub mod domain {
     }
 
     pub mod repositories {
+        pub mod unit_of_work;
         pub mod user;
     }
 
     pub mod dtos {
         pub mod user;
     }
+
+    pub mod values {
+        pub mod secret;
+        pub mod timestamp;
+    }
 }
 
 #[tokio::main]
-async fn main() {
-    let mut server = Server::new();
+async fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    if args.first().is_some_and(|arg| arg == "config") {
+        return cli::config::run(args.split_off(1));
+    }
+
+    if args.first().is_some_and(|arg| arg == "openapi") {
+        return cli::openapi::run();
+    }
+
+    let mut server = Server::with_args(args);
 
     if let Err(err) = server.run().await {
-        eprintln!("Could not run server: {err}");
+        // Configuration errors happen before logging is set up, and must still reach the operator.
+        if tracing::dispatcher::has_been_set() {
+            tracing::error!(error = %err, "Could not run server");
+        } else {
+            eprintln!("Could not run server: {err}");
+        }
+
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+pub mod test_support {
+    pub mod concurrent_sign_up;
+
+    pub mod contracts {
+        pub mod audit_log;
+        pub mod id_generator;
+        pub mod password_hasher;
+        pub mod time;
+        pub mod unit_of_work;
+        pub mod user_persistence;
     }
+
+    pub mod fakes;
+    pub mod temp_file;
+    pub mod test_app;
 }
@@ -0,0 +1,90 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    application::{
+        inputs::{auth::sign_up::SignUpInput, request::RequestMetadata},
+        ports::{
+            adapters::metrics::{MetricsPort, SignUpOutcome},
+            use_cases::auth::sign_up::SignUpPort,
+        },
+        use_cases::auth::sign_up::SignUpUseCase,
+    },
+    domain::{
+        errors::domain::DomainError, repositories::unit_of_work::UnitOfWorkPort,
+        values::timestamp::Timestamp,
+    },
+    infrastructure::adapters::audit_log::in_memory::InMemoryAuditLogAdapter,
+    test_support::fakes::{ManualClock, PlainPasswordHasher, SequentialIdGenerator},
+};
+
+const CONCURRENT_SIGN_UPS: usize = 16;
+
+struct DiscardingMetrics;
+
+impl MetricsPort for DiscardingMetrics {
+    fn observe_http_request(&self, _method: &str, _route: &str, _status: u16, _duration: Duration) {
+    }
+
+    fn count_api_request(&self, _version: &'static str) {}
+
+    fn count_sign_up(&self, _outcome: SignUpOutcome) {}
+
+    fn observe_password_hash(&self, _duration: Duration) {}
+
+    fn observe_repository_call(&self, _operation: &'static str, _duration: Duration) {}
+
+    fn count_rate_limit_store_failure(&self, _policy: &'static str) {}
+}
+
+/// Fires parallel sign-ups for the same e-mail through `unit_of_work` and asserts that exactly one
+/// of them wins while every other one is rejected with [`DomainError::UserAlreadyExists`].
+///
+/// # Panics
+///
+/// Panics if a sign-up task panics or if the outcomes differ from the expected ones.
+pub async fn assert_single_concurrent_sign_up_succeeds(unit_of_work: Arc<dyn UnitOfWorkPort>) {
+    let clock = Arc::new(ManualClock::new(Timestamp::from_unix_seconds(1_000_000)));
+    let use_case = Arc::new(SignUpUseCase::new(
+        Arc::new(SequentialIdGenerator::new("user")),
+        Arc::new(PlainPasswordHasher),
+        clock.clone(),
+        unit_of_work,
+        Arc::new(InMemoryAuditLogAdapter::new(clock)),
+        Arc::new(DiscardingMetrics),
+    ));
+
+    let barrier = Arc::new(tokio::sync::Barrier::new(CONCURRENT_SIGN_UPS));
+
+    let handles = (0..CONCURRENT_SIGN_UPS)
+        .map(|_| {
+            let use_case = Arc::clone(&use_case);
+            let barrier = Arc::clone(&barrier);
+
+            tokio::spawn(async move {
+                barrier.wait().await;
+
+                use_case
+                    .perform(SignUpInput {
+                        first_name: "John".to_string(),
+                        last_name: "Doe".to_string(),
+                        email: "john.doe@mail.com".to_string(),
+                        password: "SuperSecret123".to_string(),
+                        password_confirmation: Some("SuperSecret123".to_string()),
+                        metadata: RequestMetadata::default(),
+                    })
+                    .await
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut successes = 0;
+
+    for handle in handles {
+        match handle.await.unwrap() {
+            Ok(_) => successes += 1,
+            Err(err) => assert_eq!(err, DomainError::UserAlreadyExists),
+        }
+    }
+
+    assert_eq!(successes, 1);
+}
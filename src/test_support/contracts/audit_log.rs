@@ -0,0 +1,98 @@
+use std::path::Path;
+
+use crate::{
+    application::ports::adapters::audit_log::{
+        AuditAction, AuditEvent, AuditLogError, AuditLogPort, AuditQuery,
+    },
+    test_support::temp_file::TempFile,
+};
+
+/// Runs [`assert_contract`] as a test against the logs opened by `$open`, whose storage
+/// `$tamper` edits behind the adapter's back.
+macro_rules! audit_log_contract {
+    ($open:expr, $tamper:expr) => {
+        #[test]
+        fn should_fulfil_audit_log_contract() {
+            $crate::test_support::contracts::audit_log::assert_contract($open, $tamper);
+        }
+    };
+}
+
+pub(crate) use audit_log_contract;
+
+fn event(action: AuditAction, actor: &str) -> AuditEvent {
+    AuditEvent {
+        action,
+        actor: Some(actor.to_string()),
+        target: Some(actor.to_string()),
+        ip_address: Some("127.0.0.1".to_string()),
+        user_agent: Some("curl/8.0".to_string()),
+    }
+}
+
+/// Checks what every persistent [`AuditLogPort`] adapter must guarantee, on a log opened by
+/// `open` at a fresh path:
+/// - Entries are numbered from 1 and each one is chained to the hash of its predecessor
+/// - Queries filter entries and keep the most recent ones within their limit
+/// - A reopened log carries on the chain where it stopped
+/// - Once `tamper` changed the actor of the second entry, the chain no longer verifies and the
+///   log refuses to be opened again
+///
+/// # Panics
+///
+/// Panics if the adapter breaks any of these guarantees.
+pub fn assert_contract<A: AuditLogPort>(
+    open: impl Fn(&Path) -> Result<A, AuditLogError>,
+    tamper: impl Fn(&Path),
+) {
+    let file = TempFile::new("audit_log_contract");
+    let path = file.path();
+
+    let audit_log = open(path).unwrap();
+    let first = audit_log
+        .record(event(AuditAction::SignUp, "user_1"))
+        .unwrap();
+    let second = audit_log
+        .record(event(AuditAction::SignInFailed, "user_2"))
+        .unwrap();
+
+    assert_eq!(first.sequence, 1);
+    assert_eq!(second.sequence, 2);
+    assert_eq!(second.previous_hash, first.hash);
+    assert_eq!(audit_log.verify_chain(), Ok(()));
+
+    let by_actor = AuditQuery {
+        actor: Some("user_2".to_string()),
+        ..AuditQuery::default()
+    };
+    let latest = AuditQuery {
+        limit: Some(1),
+        ..AuditQuery::default()
+    };
+
+    assert_eq!(audit_log.query(&by_actor).unwrap(), vec![second.clone()]);
+    assert_eq!(audit_log.query(&latest).unwrap(), vec![second.clone()]);
+
+    drop(audit_log);
+
+    let third = open(path)
+        .unwrap()
+        .record(event(AuditAction::PasswordChanged, "user_1"))
+        .unwrap();
+
+    assert_eq!(third.sequence, 3);
+    assert_eq!(third.previous_hash, second.hash);
+
+    let audit_log = open(path).unwrap();
+
+    tamper(path);
+
+    assert_eq!(
+        audit_log.verify_chain(),
+        Err(AuditLogError::Tampered { sequence: 2 })
+    );
+    assert!(matches!(
+        open(path),
+        Err(AuditLogError::Tampered { sequence: 2 })
+    ));
+}
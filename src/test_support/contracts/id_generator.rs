@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+
+use crate::application::ports::adapters::id_generator::IdGeneratorPort;
+
+const THREADS: usize = 8;
+const IDS_PER_THREAD: usize = 500;
+
+/// Runs [`assert_contract`] as a test against the generator built by `$factory`.
+macro_rules! id_generator_contract {
+    ($factory:expr) => {
+        #[test]
+        fn should_fulfil_id_generator_contract() {
+            $crate::test_support::contracts::id_generator::assert_contract($factory);
+        }
+    };
+}
+
+pub(crate) use id_generator_contract;
+
+/// Checks what every [`IdGeneratorPort`] adapter must guarantee: ids are never empty, and never
+/// handed out twice, even to threads asking at the same time.
+///
+/// Generated ids also parse back to themselves, while values the adapter could not have
+/// generated are rejected.
+///
+/// # Panics
+///
+/// Panics if the adapter breaks any of these guarantees.
+pub fn assert_contract<G: IdGeneratorPort>(factory: impl Fn() -> G) {
+    let generator = factory();
+
+    let ids = std::thread::scope(|scope| {
+        let handles = (0..THREADS)
+            .map(|_| {
+                scope.spawn(|| {
+                    (0..IDS_PER_THREAD)
+                        .map(|_| generator.generate_id())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    assert!(ids.iter().all(|id| !id.is_empty()));
+    assert_eq!(
+        ids.iter().collect::<HashSet<_>>().len(),
+        THREADS * IDS_PER_THREAD
+    );
+    assert!(
+        ids.iter()
+            .all(|id| generator.parse_id(id).as_ref() == Ok(id))
+    );
+
+    for malformed in ["", "not-an-id", "../etc/passwd", "1 OR 1=1"] {
+        assert!(
+            generator.parse_id(malformed).is_err(),
+            "{malformed:?} parsed"
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use crate::application::ports::adapters::password_hasher::PasswordHasherPort;
+
+/// Runs [`assert_contract`] as a test against the hasher built by `$factory`.
+macro_rules! password_hasher_contract {
+    ($factory:expr) => {
+        #[test]
+        fn should_fulfil_password_hasher_contract() {
+            $crate::test_support::contracts::password_hasher::assert_contract($factory);
+        }
+    };
+}
+
+pub(crate) use password_hasher_contract;
+
+/// Checks what every [`PasswordHasherPort`] adapter meant for production must guarantee:
+/// - A hash verifies against its password, and against no other
+/// - The password never appears in its hash
+/// - Hashing the same password twice gives different hashes, so equal passwords cannot be spotted
+/// - Garbage in place of a hash verifies nothing, rather than panicking
+///
+/// # Panics
+///
+/// Panics if the adapter breaks any of these guarantees.
+pub fn assert_contract<H: PasswordHasherPort>(factory: impl Fn() -> H) {
+    let hasher = factory();
+    let password_hash = hasher.hash_password("SuperSecret123".to_string()).unwrap();
+
+    assert!(hasher.verify_password("SuperSecret123", &password_hash));
+    assert!(!hasher.verify_password("SuperSecret124", &password_hash));
+    assert!(!hasher.verify_password("", &password_hash));
+    assert!(!password_hash.contains("SuperSecret123"));
+
+    assert_ne!(
+        hasher.hash_password("SuperSecret123".to_string()).unwrap(),
+        password_hash
+    );
+
+    assert!(!hasher.verify_password("SuperSecret123", ""));
+    assert!(!hasher.verify_password("SuperSecret123", "SuperSecret123"));
+}
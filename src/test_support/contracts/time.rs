@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use crate::{application::ports::adapters::time::TimePort, domain::values::timestamp::Timestamp};
+
+/// Runs [`assert_contract`] as a test against the clock built by `$factory`.
+macro_rules! time_contract {
+    ($factory:expr) => {
+        #[test]
+        fn should_fulfil_time_contract() {
+            $crate::test_support::contracts::time::assert_contract($factory);
+        }
+    };
+}
+
+pub(crate) use time_contract;
+
+/// Checks what every [`TimePort`] adapter must guarantee: the time is after the Unix epoch, and
+/// neither it nor the monotonic clock goes backwards from one read to the next.
+///
+/// # Panics
+///
+/// Panics if the adapter breaks any of these guarantees.
+pub fn assert_contract<T: TimePort>(factory: impl Fn() -> T) {
+    let time = factory();
+    let start = time.monotonic_now();
+    let mut previous = time.now();
+    let mut previous_instant = start;
+
+    assert!(previous > Timestamp::UNIX_EPOCH);
+
+    for _ in 0..1_000 {
+        let now = time.now();
+        let instant = time.monotonic_now();
+
+        assert!(now >= previous, "time went back from {previous} to {now}");
+        assert!(
+            instant >= previous_instant,
+            "monotonic time went back from {previous_instant:?} to {instant:?}"
+        );
+
+        previous = now;
+        previous_instant = instant;
+    }
+
+    assert_eq!(
+        time.elapsed_since(
+            previous_instant
+                .checked_add(Duration::from_secs(1))
+                .unwrap()
+        ),
+        Duration::ZERO
+    );
+    assert!(time.elapsed_since(start) >= previous_instant.saturating_duration_since(start));
+}
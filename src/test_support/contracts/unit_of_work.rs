@@ -0,0 +1,97 @@
+use std::path::Path;
+
+use crate::{
+    domain::{
+        dtos::user::FindUserByEmailDto,
+        entities::user::{StoredUser, UserEntity},
+        repositories::{unit_of_work::UnitOfWorkPort, user::UserPersistencePort},
+        values::timestamp::Timestamp,
+    },
+    test_support::temp_file::TempFile,
+};
+
+/// Runs [`assert_contract`] as a test against the units of work built by `$factory`.
+macro_rules! unit_of_work_contract {
+    ($factory:expr) => {
+        #[test]
+        fn should_fulfil_unit_of_work_contract() {
+            $crate::test_support::contracts::unit_of_work::assert_contract($factory);
+        }
+    };
+}
+
+pub(crate) use unit_of_work_contract;
+
+fn user(id: &str, email: &str) -> UserEntity {
+    UserEntity::restore(StoredUser {
+        id: id.to_string(),
+        first_name: "John".to_string(),
+        last_name: "Doe".to_string(),
+        email: email.to_string(),
+        password_hash: format!("password_hash_of_{id}"),
+        email_verified_at: None,
+        created_at: Timestamp::UNIX_EPOCH,
+        updated_at: Timestamp::UNIX_EPOCH,
+    })
+}
+
+fn find(repository: &dyn UserPersistencePort, email: &str) -> Option<UserEntity> {
+    repository
+        .find_by_email(FindUserByEmailDto {
+            email: email.to_string(),
+        })
+        .unwrap()
+}
+
+/// Checks what every [`UnitOfWorkPort`] adapter must guarantee.
+///
+/// `factory` builds the unit of work over a fresh store at the given path, along with a
+/// repository reading that store outside of any transaction:
+/// - A transaction reads its own writes, which nobody else sees until it commits
+/// - Committed writes are seen by the store and by later transactions
+/// - Rolled back writes are seen by nobody, the transaction itself included
+///
+/// # Panics
+///
+/// Panics if the adapter breaks any of these guarantees.
+pub fn assert_contract<U: UnitOfWorkPort, R: UserPersistencePort>(
+    factory: impl Fn(&Path) -> (U, R),
+) {
+    let file = TempFile::new("unit_of_work_contract");
+    let (unit_of_work, store) = factory(file.path());
+
+    let transaction = unit_of_work.begin().unwrap();
+    let committed = user("user_1", "john.doe@mail.com");
+
+    transaction.users().create(committed.clone()).unwrap();
+
+    assert_eq!(
+        find(transaction.users().as_ref(), committed.email()),
+        Some(committed.clone())
+    );
+    assert_eq!(find(&store, committed.email()), None);
+
+    transaction.commit().unwrap();
+
+    assert_eq!(find(&store, committed.email()), Some(committed.clone()));
+
+    let transaction = unit_of_work.begin().unwrap();
+    let discarded = user("user_2", "jane.roe@mail.com");
+
+    assert_eq!(
+        find(transaction.users().as_ref(), committed.email()),
+        Some(committed)
+    );
+
+    transaction.users().create(discarded.clone()).unwrap();
+    transaction.rollback().unwrap();
+
+    assert_eq!(find(transaction.users().as_ref(), discarded.email()), None);
+    assert_eq!(find(&store, discarded.email()), None);
+
+    let transaction = unit_of_work.begin().unwrap();
+
+    assert_eq!(find(transaction.users().as_ref(), discarded.email()), None);
+
+    transaction.rollback().unwrap();
+}
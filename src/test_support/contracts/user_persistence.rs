@@ -0,0 +1,103 @@
+use crate::domain::{
+    dtos::user::FindUserByEmailDto,
+    entities::user::{StoredUser, UserEntity},
+    errors::domain::DomainError,
+    repositories::user::UserPersistencePort,
+    values::timestamp::Timestamp,
+};
+
+/// Both have a millisecond part, which adapters must keep.
+const CREATED_AT: Timestamp = Timestamp::from_unix_millis(1_000_000_123);
+const VERIFIED_AT: Timestamp = Timestamp::from_unix_millis(1_000_360_456);
+
+/// Runs [`assert_contract`] as a test against the repositories built by `$factory`.
+macro_rules! user_persistence_contract {
+    ($factory:expr) => {
+        #[test]
+        fn should_fulfil_user_persistence_contract() {
+            $crate::test_support::contracts::user_persistence::assert_contract($factory);
+        }
+    };
+}
+
+pub(crate) use user_persistence_contract;
+
+fn user(id: &str, email: &str) -> UserEntity {
+    UserEntity::restore(StoredUser {
+        id: id.to_string(),
+        first_name: "John".to_string(),
+        last_name: "Doe".to_string(),
+        email: email.to_string(),
+        password_hash: format!("password_hash_of_{id}"),
+        email_verified_at: None,
+        created_at: CREATED_AT,
+        updated_at: CREATED_AT,
+    })
+}
+
+fn find(repository: &impl UserPersistencePort, email: &str) -> Option<UserEntity> {
+    repository
+        .find_by_email(FindUserByEmailDto {
+            email: email.to_string(),
+        })
+        .unwrap()
+}
+
+/// Checks what every [`UserPersistencePort`] adapter must guarantee, each case on a fresh
+/// repository from `factory`:
+/// - Created users are returned as stored, and found again by e-mail with every field intact,
+///   password hash included
+/// - Unknown e-mails are not found, rather than failing
+/// - An e-mail can only be claimed once, the loser getting a [`DomainError::Conflict`]
+///
+/// # Panics
+///
+/// Panics if the adapter breaks any of these guarantees.
+pub fn assert_contract<P: UserPersistencePort>(factory: impl Fn() -> P) {
+    let repository = factory();
+    let unverified = user("user_1", "john.doe@mail.com");
+    let verified = UserEntity::restore(StoredUser {
+        id: "user_2".to_string(),
+        first_name: "Jane".to_string(),
+        last_name: "Roe".to_string(),
+        email: "jane.roe@mail.com".to_string(),
+        password_hash: "password_hash_of_user_2".to_string(),
+        email_verified_at: Some(VERIFIED_AT),
+        created_at: CREATED_AT,
+        updated_at: VERIFIED_AT,
+    });
+
+    for expected in [unverified, verified] {
+        assert_eq!(repository.create(expected.clone()).unwrap(), expected);
+        assert_eq!(find(&repository, expected.email()), Some(expected));
+    }
+
+    let repository = factory();
+
+    repository
+        .create(user("user_1", "john.doe@mail.com"))
+        .unwrap();
+
+    assert_eq!(find(&repository, "jane.doe@mail.com"), None);
+    assert_eq!(find(&repository, "JOHN.DOE@mail.com"), None);
+
+    let repository = factory();
+
+    repository
+        .create(user("user_1", "john.doe@mail.com"))
+        .unwrap();
+
+    assert!(matches!(
+        repository.create(user("user_2", "john.doe@mail.com")),
+        Err(DomainError::Conflict(_))
+    ));
+    assert_eq!(
+        find(&repository, "john.doe@mail.com").map(|user| user.id().to_string()),
+        Some("user_1".to_string())
+    );
+    assert!(
+        repository
+            .create(user("user_3", "jane.doe@mail.com"))
+            .is_ok()
+    );
+}
@@ -0,0 +1,167 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicI64, AtomicU64, AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use crate::{
+    application::ports::adapters::{
+        env::{ConfigSource, EnvError, EnvPort, ResolvedValue},
+        id_generator::{IdGeneratorPort, InvalidIdError},
+        password_hasher::{PasswordHashError, PasswordHasherPort},
+        time::{MonotonicInstant, TimePort},
+    },
+    domain::values::timestamp::Timestamp,
+};
+
+/// A clock that only moves when told to.
+///
+/// Advancing it moves both its wall-clock and monotonic readings, while setting it only moves the
+/// wall clock, as a correction of the system time would.
+pub struct ManualClock {
+    now: AtomicI64,
+    monotonic_nanos: AtomicU64,
+}
+
+impl ManualClock {
+    #[must_use]
+    pub const fn new(now: Timestamp) -> Self {
+        Self {
+            now: AtomicI64::new(now.unix_millis()),
+            monotonic_nanos: AtomicU64::new(0),
+        }
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        self.now.fetch_add(
+            i64::try_from(duration.as_millis()).unwrap_or(i64::MAX),
+            Ordering::SeqCst,
+        );
+        self.monotonic_nanos.fetch_add(
+            u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX),
+            Ordering::SeqCst,
+        );
+    }
+
+    pub fn set(&self, now: Timestamp) {
+        self.now.store(now.unix_millis(), Ordering::SeqCst);
+    }
+}
+
+impl TimePort for ManualClock {
+    fn now(&self) -> Timestamp {
+        Timestamp::from_unix_millis(self.now.load(Ordering::SeqCst))
+    }
+
+    fn monotonic_now(&self) -> MonotonicInstant {
+        MonotonicInstant::from_origin(Duration::from_nanos(
+            self.monotonic_nanos.load(Ordering::SeqCst),
+        ))
+    }
+}
+
+/// Hands out `<prefix>_1`, `<prefix>_2` and so on, and accepts any id of that shape.
+pub struct SequentialIdGenerator {
+    prefix: &'static str,
+    next_id: AtomicUsize,
+}
+
+impl SequentialIdGenerator {
+    #[must_use]
+    pub const fn new(prefix: &'static str) -> Self {
+        Self {
+            prefix,
+            next_id: AtomicUsize::new(1),
+        }
+    }
+}
+
+impl IdGeneratorPort for SequentialIdGenerator {
+    fn generate_id(&self) -> String {
+        format!(
+            "{}_{}",
+            self.prefix,
+            self.next_id.fetch_add(1, Ordering::SeqCst)
+        )
+    }
+
+    fn parse_id(&self, value: &str) -> Result<String, InvalidIdError> {
+        value
+            .strip_prefix(self.prefix)
+            .and_then(|rest| rest.strip_prefix('_'))
+            .and_then(|sequence| sequence.parse::<usize>().ok())
+            .map(|sequence| format!("{}_{sequence}", self.prefix))
+            .ok_or_else(|| InvalidIdError {
+                value: value.to_string(),
+                expected: "sequential id",
+            })
+    }
+}
+
+/// Stores passwords as they are, so tests stay fast and can read them back.
+pub struct PlainPasswordHasher;
+
+impl PasswordHasherPort for PlainPasswordHasher {
+    fn hash_password(&self, password: String) -> Result<String, PasswordHashError> {
+        Ok(password)
+    }
+
+    fn verify_password(&self, password: &str, password_hash: &str) -> bool {
+        password == password_hash
+    }
+}
+
+/// Resolves settings from a fixed map, as if they were set in the process environment.
+pub struct FakeEnv {
+    variables: HashMap<String, ResolvedValue>,
+}
+
+impl FakeEnv {
+    #[must_use]
+    pub fn new(variables: &[(&str, &str)]) -> Self {
+        variables.iter().fold(
+            Self {
+                variables: HashMap::new(),
+            },
+            |env, (key, value)| env.with_source(key, value, ConfigSource::Environment),
+        )
+    }
+
+    /// Resolves `key` to `value` as if it came from `source` instead of the environment.
+    #[must_use]
+    pub fn with_source(mut self, key: &str, value: &str, source: ConfigSource) -> Self {
+        self.variables.insert(
+            key.to_string(),
+            ResolvedValue {
+                value: value.to_string(),
+                source,
+            },
+        );
+
+        self
+    }
+}
+
+impl EnvPort for FakeEnv {
+    fn load_env_file(&mut self) -> Result<(), EnvError> {
+        Ok(())
+    }
+
+    fn lookup(&self, key: &str) -> Result<Option<ResolvedValue>, EnvError> {
+        Ok(self.variables.get(key).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        domain::values::timestamp::Timestamp,
+        test_support::{
+            contracts::{id_generator::id_generator_contract, time::time_contract},
+            fakes::{ManualClock, SequentialIdGenerator},
+        },
+    };
+
+    id_generator_contract!(|| SequentialIdGenerator::new("user"));
+    time_contract!(|| ManualClock::new(Timestamp::from_unix_seconds(1_000_000)));
+}
@@ -0,0 +1,33 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Tells apart the files of tests sharing the process.
+static FILES: AtomicUsize = AtomicUsize::new(0);
+
+/// A path in the temporary directory, unique to this process and test, whose file is removed on
+/// drop. The file itself is left for the code under test to create.
+pub struct TempFile(PathBuf);
+
+impl TempFile {
+    #[must_use]
+    pub fn new(prefix: &str) -> Self {
+        Self(std::env::temp_dir().join(format!(
+            "axum_tdd_api_{prefix}_{}_{}",
+            std::process::id(),
+            FILES.fetch_add(1, Ordering::Relaxed)
+        )))
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
@@ -0,0 +1,436 @@
+use std::{
+    collections::BTreeMap,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use axum::{
+    Router,
+    body::{Body, to_bytes},
+    extract::ConnectInfo,
+    http::{
+        HeaderMap, HeaderName, HeaderValue, Method, Request, StatusCode,
+        header::{AUTHORIZATION, CONTENT_TYPE, COOKIE, SET_COOKIE},
+    },
+};
+use serde::Serialize;
+use tower::ServiceExt;
+
+use crate::{
+    application::ports::adapters::audit_log::{AuditAction, AuditEntry},
+    composition::bootstrap::app::{AppBuilder, AppRouters},
+    domain::values::timestamp::Timestamp,
+    infrastructure::{
+        adapters::{
+            audit_log::in_memory::InMemoryAuditLogAdapter,
+            rate_limit::in_memory::InMemoryRateLimitStore,
+        },
+        http::peer::PeerAddr,
+        repositories::in_memory::{unit_of_work::InMemoryUnitOfWork, user::InMemoryUserRepository},
+    },
+    test_support::fakes::{FakeEnv, ManualClock, PlainPasswordHasher, SequentialIdGenerator},
+};
+
+/// Where the clock of every [`TestApp`] starts.
+pub const START_TIME: Timestamp = Timestamp::from_unix_seconds(1_700_000_000);
+
+const DEFAULT_PEER: &str = "203.0.113.10:40000";
+
+/// The whole application wired to in-memory adapters, a [`ManualClock`] and sequential ids,
+/// `user_<n>` for users and `request_<n>` for requests, driven in-process without binding a
+/// socket.
+pub struct TestApp {
+    pub clock: Arc<ManualClock>,
+    pub users: InMemoryUserRepository,
+    pub audit_log: Arc<InMemoryAuditLogAdapter>,
+    routers: AppRouters,
+}
+
+impl TestApp {
+    /// # Panics
+    ///
+    /// Panics if the application cannot be built.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_settings(&[])
+    }
+
+    /// Boots the application with `settings` on top of the defaults, as if they were set in the
+    /// environment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a setting is invalid or the application cannot be built.
+    #[must_use]
+    pub fn with_settings(settings: &[(&str, &str)]) -> Self {
+        let mut variables = vec![("SERVER_HOST", "127.0.0.1"), ("SERVER_PORT", "8080")];
+
+        variables.extend_from_slice(settings);
+
+        let clock = Arc::new(ManualClock::new(START_TIME));
+        let users = InMemoryUserRepository::new();
+        let audit_log = Arc::new(InMemoryAuditLogAdapter::new(clock.clone()));
+
+        let routers = AppBuilder::from_env(&FakeEnv::new(&variables))
+            .expect("test settings are invalid")
+            .with_id_generator(Arc::new(SequentialIdGenerator::new("user")))
+            .with_request_id_generator(Arc::new(SequentialIdGenerator::new("request")))
+            .with_password_hasher(Arc::new(PlainPasswordHasher))
+            .with_time(clock.clone())
+            .with_unit_of_work(Arc::new(InMemoryUnitOfWork::new(&users)))
+            .with_audit_log(audit_log.clone())
+            .with_rate_limit_store(Arc::new(InMemoryRateLimitStore::new()))
+            .with_health_checks(Vec::new())
+            .build()
+            .expect("test application could not be built");
+
+        Self {
+            clock,
+            users,
+            audit_log,
+            routers,
+        }
+    }
+
+    /// A client of the public listener, with its own cookie jar.
+    #[must_use]
+    pub fn client(&self) -> TestClient {
+        TestClient::new(self.routers.public.clone())
+    }
+
+    /// A client of the internal listener, with its own cookie jar.
+    #[must_use]
+    pub fn internal_client(&self) -> TestClient {
+        TestClient::new(self.routers.internal.clone())
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the audit log is poisoned.
+    #[must_use]
+    pub fn audit_entries(&self) -> Vec<AuditEntry> {
+        self.audit_log.entries().expect("audit log is poisoned")
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the audit log is poisoned.
+    #[must_use]
+    pub fn audited_actions(&self) -> Vec<AuditAction> {
+        self.audit_entries()
+            .into_iter()
+            .map(|entry| entry.action)
+            .collect()
+    }
+}
+
+impl Default for TestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sends requests to a router the way a browser or API client would: cookies set by responses are
+/// sent back, and a bearer token, once given, goes with every request.
+#[derive(Clone)]
+pub struct TestClient {
+    router: Router,
+    cookies: Arc<Mutex<BTreeMap<String, String>>>,
+    bearer: Option<String>,
+    peer: SocketAddr,
+}
+
+impl TestClient {
+    /// # Panics
+    ///
+    /// Never in practice: the default peer address is a valid literal.
+    #[must_use]
+    pub fn new(router: Router) -> Self {
+        Self {
+            router,
+            cookies: Arc::new(Mutex::new(BTreeMap::new())),
+            bearer: None,
+            peer: DEFAULT_PEER.parse().expect("default peer is invalid"),
+        }
+    }
+
+    #[must_use]
+    pub fn with_bearer(mut self, token: impl Into<String>) -> Self {
+        self.bearer = Some(token.into());
+        self
+    }
+
+    /// The address requests appear to come from, which rate limits and audit entries record.
+    #[must_use]
+    pub const fn with_peer(mut self, peer: SocketAddr) -> Self {
+        self.peer = peer;
+        self
+    }
+
+    #[must_use]
+    pub fn get(&self, path: &str) -> TestRequest {
+        self.request(Method::GET, path)
+    }
+
+    #[must_use]
+    pub fn post(&self, path: &str) -> TestRequest {
+        self.request(Method::POST, path)
+    }
+
+    #[must_use]
+    pub fn put(&self, path: &str) -> TestRequest {
+        self.request(Method::PUT, path)
+    }
+
+    #[must_use]
+    pub fn delete(&self, path: &str) -> TestRequest {
+        self.request(Method::DELETE, path)
+    }
+
+    #[must_use]
+    pub fn request(&self, method: Method, path: &str) -> TestRequest {
+        TestRequest {
+            client: self.clone(),
+            method,
+            path: path.to_string(),
+            headers: HeaderMap::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the cookie jar is poisoned.
+    #[must_use]
+    pub fn cookie(&self, name: &str) -> Option<String> {
+        self.cookies
+            .lock()
+            .expect("cookie jar is poisoned")
+            .get(name)
+            .cloned()
+    }
+
+    fn store_cookies(&self, headers: &HeaderMap) {
+        let mut cookies = self.cookies.lock().expect("cookie jar is poisoned");
+
+        for set_cookie in headers.get_all(SET_COOKIE) {
+            let Some((name, rest)) = set_cookie
+                .to_str()
+                .ok()
+                .and_then(|value| value.split_once('='))
+            else {
+                continue;
+            };
+            let mut attributes = rest.split(';').map(str::trim);
+            let value = attributes.next().unwrap_or_default();
+
+            // An empty value or a zero lifetime is how a server deletes a cookie.
+            if value.is_empty()
+                || attributes.any(|attribute| attribute.eq_ignore_ascii_case("max-age=0"))
+            {
+                cookies.remove(name.trim());
+            } else {
+                cookies.insert(name.trim().to_string(), value.to_string());
+            }
+        }
+    }
+
+    fn cookie_header(&self) -> Option<String> {
+        let cookies = self.cookies.lock().expect("cookie jar is poisoned");
+
+        (!cookies.is_empty()).then(|| {
+            cookies
+                .iter()
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect::<Vec<_>>()
+                .join("; ")
+        })
+    }
+}
+
+pub struct TestRequest {
+    client: TestClient,
+    method: Method,
+    path: String,
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+impl TestRequest {
+    /// # Panics
+    ///
+    /// Panics if `name` or `value` is not a valid header.
+    #[must_use]
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(
+            HeaderName::try_from(name).expect("invalid header name"),
+            HeaderValue::try_from(value).expect("invalid header value"),
+        );
+        self
+    }
+
+    /// Authenticates this request only, in place of the client's token.
+    #[must_use]
+    pub fn bearer(self, token: &str) -> Self {
+        self.header(AUTHORIZATION.as_str(), &format!("Bearer {token}"))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `body` cannot be serialized.
+    #[must_use]
+    pub fn json(mut self, body: &impl Serialize) -> Self {
+        self.body = serde_json::to_vec(body).expect("request body is not serializable");
+        self.headers
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the request cannot be built or the response body cannot be read.
+    pub async fn send(self) -> TestResponse {
+        let mut request = Request::builder()
+            .method(self.method)
+            .uri(&self.path)
+            .body(Body::from(self.body))
+            .expect("invalid request");
+
+        if let Some(token) = &self.client.bearer {
+            request.headers_mut().insert(
+                AUTHORIZATION,
+                HeaderValue::try_from(format!("Bearer {token}")).expect("invalid bearer token"),
+            );
+        }
+
+        if let Some(cookies) = self.client.cookie_header() {
+            request.headers_mut().insert(
+                COOKIE,
+                HeaderValue::try_from(cookies).expect("invalid cookie"),
+            );
+        }
+
+        request.headers_mut().extend(self.headers);
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(PeerAddr::Tcp(self.client.peer)));
+
+        let response = self
+            .client
+            .router
+            .clone()
+            .oneshot(request)
+            .await
+            .expect("router is infallible");
+
+        self.client.store_cookies(response.headers());
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("response body could not be read");
+
+        TestResponse {
+            status,
+            headers,
+            body: String::from_utf8_lossy(&body).into_owned(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TestResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl TestResponse {
+    /// # Panics
+    ///
+    /// Panics, showing the body, if the status differs from `expected`.
+    #[track_caller]
+    #[must_use]
+    pub fn assert_status(self, expected: StatusCode) -> Self {
+        assert_eq!(
+            self.status, expected,
+            "unexpected status, response body: {}",
+            self.body
+        );
+        self
+    }
+
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the body is not JSON.
+    #[track_caller]
+    #[must_use]
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_str(&self.body)
+            .unwrap_or_else(|err| panic!("response body is not JSON ({err}): {}", self.body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        http::{HeaderMap, StatusCode, header::SET_COOKIE},
+        routing::{get, post},
+    };
+
+    use crate::test_support::test_app::TestClient;
+
+    fn echo(headers: &HeaderMap, name: &str) -> String {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("none")
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn should_send_back_cookies_and_bearer_tokens() {
+        let router = Router::new()
+            .route(
+                "/session",
+                post(|| async { [(SET_COOKIE, "session=abc; Path=/; HttpOnly")] })
+                    .delete(|| async { [(SET_COOKIE, "session=; Max-Age=0")] }),
+            )
+            .route(
+                "/echo",
+                get(|headers: HeaderMap| async move {
+                    format!(
+                        "{} {}",
+                        echo(&headers, "cookie"),
+                        echo(&headers, "authorization")
+                    )
+                }),
+            );
+
+        let client = TestClient::new(router).with_bearer("token");
+
+        assert_eq!(client.post("/session").send().await.status, StatusCode::OK);
+
+        assert_eq!(client.cookie("session").as_deref(), Some("abc"));
+        assert_eq!(
+            client.get("/echo").send().await.body,
+            "session=abc Bearer token"
+        );
+        assert_eq!(
+            client.get("/echo").bearer("other").send().await.body,
+            "session=abc Bearer other"
+        );
+
+        client.delete("/session").send().await;
+
+        assert_eq!(client.cookie("session"), None);
+        assert_eq!(client.get("/echo").send().await.body, "none Bearer token");
+    }
+}